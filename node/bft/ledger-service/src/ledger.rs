@@ -76,6 +76,11 @@ impl<N: Network, C: ConsensusStorage<N>> LedgerService<N> for CoreLedgerService<
         self.ledger.latest_height()
     }
 
+    /// Returns the latest block hash in the ledger.
+    fn latest_block_hash(&self) -> N::BlockHash {
+        self.ledger.latest_hash()
+    }
+
     /// Returns the latest block in the ledger.
     fn latest_block(&self) -> Block<N> {
         self.ledger.latest_block()
@@ -122,11 +127,21 @@ impl<N: Network, C: ConsensusStorage<N>> LedgerService<N> for CoreLedgerService<
         self.ledger.get_blocks(heights)
     }
 
+    /// Returns the hash of the block containing the given transaction ID, if it exists.
+    fn find_block_hash(&self, transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>> {
+        self.ledger.find_block_hash(transaction_id)
+    }
+
     /// Returns the solution for the given solution ID.
     fn get_solution(&self, solution_id: &SolutionID<N>) -> Result<Solution<N>> {
         self.ledger.get_solution(solution_id)
     }
 
+    /// Returns the proof target of the given solution.
+    fn get_proof_target(&self, solution: &Solution<N>) -> Result<u64> {
+        self.ledger.puzzle().get_proof_target(solution)
+    }
+
     /// Returns the unconfirmed transaction for the given transaction ID.
     fn get_unconfirmed_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>> {
         self.ledger.get_unconfirmed_transaction(&transaction_id)
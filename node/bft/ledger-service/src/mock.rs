@@ -63,6 +63,15 @@ impl<N: Network> LedgerService<N> for MockLedgerService<N> {
         self.height_to_round_and_hash.lock().last_key_value().map(|(height, _)| *height).unwrap_or(0)
     }
 
+    /// Returns the latest block hash in the ledger.
+    fn latest_block_hash(&self) -> N::BlockHash {
+        self.height_to_round_and_hash
+            .lock()
+            .last_key_value()
+            .map(|(_, (_, hash))| *hash)
+            .unwrap_or_else(|| Field::<N>::from_u32(0).into())
+    }
+
     /// Returns the latest block in the ledger.
     fn latest_block(&self) -> Block<N> {
         unreachable!("MockLedgerService does not support latest_block")
@@ -126,11 +135,21 @@ impl<N: Network> LedgerService<N> for MockLedgerService<N> {
         unreachable!("MockLedgerService does not support get_blocks")
     }
 
+    /// Returns the hash of the block containing the given transaction ID, if it exists.
+    fn find_block_hash(&self, _transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>> {
+        unreachable!("MockLedgerService does not support find_block_hash")
+    }
+
     /// Returns the solution for the given solution ID.
     fn get_solution(&self, _solution_id: &SolutionID<N>) -> Result<Solution<N>> {
         unreachable!("MockLedgerService does not support get_solution")
     }
 
+    /// Returns the proof target of the given solution.
+    fn get_proof_target(&self, _solution: &Solution<N>) -> Result<u64> {
+        unreachable!("MockLedgerService does not support get_proof_target")
+    }
+
     /// Returns the unconfirmed transaction for the given transaction ID.
     fn get_unconfirmed_transaction(&self, _transaction_id: N::TransactionID) -> Result<Transaction<N>> {
         unreachable!("MockLedgerService does not support get_unconfirmed_transaction")
@@ -222,4 +241,17 @@ impl<N: Network> LedgerService<N> for MockLedgerService<N> {
         self.height_to_round_and_hash.lock().insert(block.height(), (block.round(), block.hash()));
         Ok(())
     }
+
+    /// Returns a fork of the ledger, which shares the blocks up to and including the given block height.
+    #[cfg(feature = "test")]
+    fn fork_at(&self, height: u32) -> Result<std::sync::Arc<dyn LedgerService<N>>> {
+        let height_to_round_and_hash = self.height_to_round_and_hash.lock();
+        ensure!(height_to_round_and_hash.contains_key(&height), "Cannot fork the ledger at missing block {height}");
+        // Copy the blocks up to and including the given height.
+        let forked = height_to_round_and_hash.range(..=height).map(|(height, entry)| (*height, *entry)).collect();
+        Ok(std::sync::Arc::new(Self {
+            committee: self.committee.clone(),
+            height_to_round_and_hash: Mutex::new(forked),
+        }))
+    }
 }
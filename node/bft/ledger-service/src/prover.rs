@@ -51,6 +51,11 @@ impl<N: Network> LedgerService<N> for ProverLedgerService<N> {
         0u32
     }
 
+    /// Returns the latest block hash in the ledger.
+    fn latest_block_hash(&self) -> N::BlockHash {
+        unreachable!("Latest block hash does not exist in prover")
+    }
+
     /// Returns the latest block in the ledger.
     fn latest_block(&self) -> Block<N> {
         unreachable!("Latest block does not exist in prover")
@@ -97,11 +102,21 @@ impl<N: Network> LedgerService<N> for ProverLedgerService<N> {
         bail!("Blocks {heights:?} do not exist in prover")
     }
 
+    /// Returns the hash of the block containing the given transaction ID, if it exists.
+    fn find_block_hash(&self, transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>> {
+        bail!("Transaction '{transaction_id}' does not exist in prover")
+    }
+
     /// Returns the solution for the given solution ID.
     fn get_solution(&self, solution_id: &SolutionID<N>) -> Result<Solution<N>> {
         bail!("Solution '{solution_id}' does not exist in prover")
     }
 
+    /// Returns the proof target of the given solution.
+    fn get_proof_target(&self, solution: &Solution<N>) -> Result<u64> {
+        bail!("Proof target for solution '{}' is not available in prover", solution.id())
+    }
+
     /// Returns the unconfirmed transaction for the given transaction ID.
     fn get_unconfirmed_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>> {
         bail!("Transaction '{transaction_id}' does not exist in prover")
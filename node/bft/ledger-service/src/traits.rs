@@ -33,6 +33,9 @@ pub trait LedgerService<N: Network>: Debug + Send + Sync {
     /// Returns the latest block height in the ledger.
     fn latest_block_height(&self) -> u32;
 
+    /// Returns the latest block hash in the ledger.
+    fn latest_block_hash(&self) -> N::BlockHash;
+
     /// Returns the latest block in the ledger.
     fn latest_block(&self) -> Block<N>;
 
@@ -61,9 +64,15 @@ pub trait LedgerService<N: Network>: Debug + Send + Sync {
     /// The range is inclusive of the start and exclusive of the end.
     fn get_blocks(&self, heights: Range<u32>) -> Result<Vec<Block<N>>>;
 
+    /// Returns the hash of the block containing the given transaction ID, if it exists.
+    fn find_block_hash(&self, transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>>;
+
     /// Returns the solution for the given solution ID.
     fn get_solution(&self, solution_id: &SolutionID<N>) -> Result<Solution<N>>;
 
+    /// Returns the proof target of the given solution.
+    fn get_proof_target(&self, solution: &Solution<N>) -> Result<u64>;
+
     /// Returns the unconfirmed transaction for the given transaction ID.
     fn get_unconfirmed_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>>;
 
@@ -118,4 +127,14 @@ pub trait LedgerService<N: Network>: Debug + Send + Sync {
     /// Adds the given block as the next block in the ledger.
     #[cfg(feature = "ledger-write")]
     fn advance_to_next_block(&self, block: &Block<N>) -> Result<()>;
+
+    /// Returns a fork of the ledger, which shares the blocks up to and including the given block height,
+    /// and can be advanced independently of this ledger.
+    /// Note: This is only available for testing, and returns an error unless the ledger service supports snapshots.
+    #[cfg(feature = "test")]
+    fn fork_at(&self, height: u32) -> Result<std::sync::Arc<dyn LedgerService<N>>> {
+        snarkvm::prelude::bail!(
+            "Cannot fork the ledger at block {height} - The ledger service does not support snapshots"
+        )
+    }
 }
@@ -62,6 +62,11 @@ impl<N: Network, C: ConsensusStorage<N>> LedgerService<N> for TranslucentLedgerS
         self.inner.latest_block_height()
     }
 
+    /// Returns the latest block hash in the ledger.
+    fn latest_block_hash(&self) -> N::BlockHash {
+        self.inner.latest_block_hash()
+    }
+
     /// Returns the latest block in the ledger.
     fn latest_block(&self) -> Block<N> {
         self.inner.latest_block()
@@ -108,11 +113,21 @@ impl<N: Network, C: ConsensusStorage<N>> LedgerService<N> for TranslucentLedgerS
         self.inner.get_blocks(heights)
     }
 
+    /// Returns the hash of the block containing the given transaction ID, if it exists.
+    fn find_block_hash(&self, transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>> {
+        self.inner.find_block_hash(transaction_id)
+    }
+
     /// Returns the solution for the given solution ID.
     fn get_solution(&self, solution_id: &SolutionID<N>) -> Result<Solution<N>> {
         self.inner.get_solution(solution_id)
     }
 
+    /// Returns the proof target of the given solution.
+    fn get_proof_target(&self, solution: &Solution<N>) -> Result<u64> {
+        self.inner.get_proof_target(solution)
+    }
+
     /// Returns the unconfirmed transaction for the given transaction ID.
     fn get_unconfirmed_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>> {
         self.inner.get_unconfirmed_transaction(transaction_id)
@@ -190,4 +205,10 @@ impl<N: Network, C: ConsensusStorage<N>> LedgerService<N> for TranslucentLedgerS
     fn advance_to_next_block(&self, block: &Block<N>) -> Result<()> {
         self.inner.advance_to_next_block(block)
     }
+
+    /// Returns a fork of the ledger, which shares the blocks up to and including the given block height.
+    #[cfg(feature = "test")]
+    fn fork_at(&self, height: u32) -> Result<Arc<dyn LedgerService<N>>> {
+        self.inner.fork_at(height)
+    }
 }
@@ -147,6 +147,11 @@ impl<N: Network> BFT<N> {
     pub const fn leader_certificate(&self) -> &Arc<RwLock<Option<BatchCertificate<N>>>> {
         &self.leader_certificate
     }
+
+    /// Returns the last round committed by the DAG.
+    pub fn last_committed_round(&self) -> u64 {
+        self.dag.read().last_committed_round()
+    }
 }
 
 impl<N: Network> BFT<N> {
@@ -169,6 +174,11 @@ impl<N: Network> BFT<N> {
     pub fn num_unconfirmed_transactions(&self) -> usize {
         self.primary.num_unconfirmed_transactions()
     }
+
+    /// Returns `true` if the transmission ID exists in the ready queue, proposed batch, storage, or ledger.
+    pub fn contains_transmission(&self, transmission_id: impl Into<TransmissionID<N>>) -> bool {
+        self.primary.contains_transmission(transmission_id)
+    }
 }
 
 impl<N: Network> BFT<N> {
@@ -182,6 +192,24 @@ impl<N: Network> BFT<N> {
         self.primary.unconfirmed_transmissions()
     }
 
+    /// Returns up to `limit` unconfirmed transmissions after the given transmission ID, or from the beginning
+    /// if there is none. Returns `None` if the given transmission ID is not in the ready queue.
+    pub fn unconfirmed_transmissions_after(
+        &self,
+        transmission_id: Option<TransmissionID<N>>,
+        limit: usize,
+    ) -> Option<Vec<(TransmissionID<N>, Transmission<N>)>> {
+        self.primary.unconfirmed_transmissions_after(transmission_id, limit)
+    }
+
+    /// Returns the unconfirmed transmission for the given transmission ID, if it exists in the ready queue.
+    pub fn get_unconfirmed_transmission(
+        &self,
+        transmission_id: impl Into<TransmissionID<N>>,
+    ) -> Option<Transmission<N>> {
+        self.primary.get_unconfirmed_transmission(transmission_id)
+    }
+
     /// Returns the unconfirmed solutions.
     pub fn unconfirmed_solutions(&self) -> impl '_ + Iterator<Item = (SolutionID<N>, Data<Solution<N>>)> {
         self.primary.unconfirmed_solutions()
@@ -191,6 +219,17 @@ impl<N: Network> BFT<N> {
     pub fn unconfirmed_transactions(&self) -> impl '_ + Iterator<Item = (N::TransactionID, Data<Transaction<N>>)> {
         self.primary.unconfirmed_transactions()
     }
+
+    /// Removes all the transmissions from the ready queues of the workers, and returns them.
+    pub fn drain_unconfirmed_transmissions(&self) -> IndexMap<TransmissionID<N>, Transmission<N>> {
+        self.primary.drain_unconfirmed_transmissions()
+    }
+
+    /// Removes the given transaction from the ready queues of the workers.
+    /// Returns `true` if the transaction was found and removed.
+    pub fn remove_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> bool {
+        self.primary.remove_unconfirmed_transaction(transaction_id)
+    }
 }
 
 impl<N: Network> BFT<N> {
@@ -28,14 +28,15 @@ use snarkvm::{
         narwhal::{BatchCertificate, Data, Subdag, Transmission, TransmissionID},
         puzzle::{Solution, SolutionID},
     },
-    prelude::Result,
+    prelude::{bail, Result},
 };
 
 use indexmap::IndexMap;
-use std::net::SocketAddr;
-use tokio::sync::{mpsc, oneshot};
+use std::{net::SocketAddr, time::Duration};
+use tokio::sync::{mpsc, mpsc::error::SendTimeoutError, oneshot};
 
-const MAX_CHANNEL_SIZE: usize = 8192;
+/// The default capacity of the channels.
+pub const MAX_CHANNEL_SIZE: usize = 8192;
 
 #[derive(Debug)]
 pub struct ConsensusSender<N: Network> {
@@ -51,7 +52,14 @@ pub struct ConsensusReceiver<N: Network> {
 
 /// Initializes the consensus channels.
 pub fn init_consensus_channels<N: Network>() -> (ConsensusSender<N>, ConsensusReceiver<N>) {
-    let (tx_consensus_subdag, rx_consensus_subdag) = mpsc::channel(MAX_CHANNEL_SIZE);
+    init_consensus_channels_with_capacity(MAX_CHANNEL_SIZE)
+}
+
+/// Initializes the consensus channels, with the given capacity.
+pub fn init_consensus_channels_with_capacity<N: Network>(
+    capacity: usize,
+) -> (ConsensusSender<N>, ConsensusReceiver<N>) {
+    let (tx_consensus_subdag, rx_consensus_subdag) = mpsc::channel(capacity);
 
     let sender = ConsensusSender { tx_consensus_subdag };
     let receiver = ConsensusReceiver { rx_consensus_subdag };
@@ -158,6 +166,28 @@ impl<N: Network> PrimarySender<N> {
         // Await the callback to continue.
         callback_receiver.await?
     }
+
+    /// Sends the unconfirmed transaction to the primary, waiting at most `timeout` for the channel to accept it.
+    /// Returns the transaction back if the channel is still full after the timeout, and `None` once it is processed.
+    pub async fn send_unconfirmed_transaction_with_timeout(
+        &self,
+        transaction_id: N::TransactionID,
+        transaction: Data<Transaction<N>>,
+        timeout: Duration,
+    ) -> Result<Option<Data<Transaction<N>>>> {
+        // Initialize a callback sender and receiver.
+        let (callback_sender, callback_receiver) = oneshot::channel();
+        // Send the unconfirmed transaction to the primary, within the timeout.
+        let message = (transaction_id, transaction, callback_sender);
+        match self.tx_unconfirmed_transaction.send_timeout(message, timeout).await {
+            Ok(()) => (),
+            Err(SendTimeoutError::Timeout((_, transaction, _))) => return Ok(Some(transaction)),
+            Err(SendTimeoutError::Closed(_)) => bail!("The primary channel for unconfirmed transactions is closed"),
+        }
+        // Await the callback to continue.
+        callback_receiver.await??;
+        Ok(None)
+    }
 }
 
 #[derive(Debug)]
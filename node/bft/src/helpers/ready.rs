@@ -23,12 +23,21 @@ use snarkvm::{
 
 use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 
 #[derive(Clone, Debug)]
 pub struct Ready<N: Network> {
     /// The current map of `(transmission ID, transmission)` entries.
     transmissions: Arc<RwLock<IndexMap<TransmissionID<N>, Transmission<N>>>>,
+    /// The number of solutions in the ready queue.
+    /// Note: This counter is only updated while holding the write lock on the transmissions.
+    num_solutions: Arc<AtomicUsize>,
+    /// The number of transactions in the ready queue.
+    /// Note: This counter is only updated while holding the write lock on the transmissions.
+    num_transactions: Arc<AtomicUsize>,
 }
 
 impl<N: Network> Default for Ready<N> {
@@ -41,7 +50,11 @@ impl<N: Network> Default for Ready<N> {
 impl<N: Network> Ready<N> {
     /// Initializes a new instance of the ready queue.
     pub fn new() -> Self {
-        Self { transmissions: Default::default() }
+        Self {
+            transmissions: Default::default(),
+            num_solutions: Default::default(),
+            num_transactions: Default::default(),
+        }
     }
 
     /// Returns `true` if the ready queue is empty.
@@ -61,12 +74,12 @@ impl<N: Network> Ready<N> {
 
     /// Returns the number of solutions in the ready queue.
     pub fn num_solutions(&self) -> usize {
-        self.transmissions.read().keys().filter(|id| matches!(id, TransmissionID::Solution(..))).count()
+        self.num_solutions.load(Ordering::Relaxed)
     }
 
     /// Returns the number of transactions in the ready queue.
     pub fn num_transactions(&self) -> usize {
-        self.transmissions.read().keys().filter(|id| matches!(id, TransmissionID::Transaction(..))).count()
+        self.num_transactions.load(Ordering::Relaxed)
     }
 
     /// Returns the transmission IDs in the ready queue.
@@ -79,6 +92,25 @@ impl<N: Network> Ready<N> {
         self.transmissions.read().clone()
     }
 
+    /// Returns up to `limit` transmissions after the given transmission ID, or from the beginning if there is none,
+    /// in insertion order. Returns `None` if the given transmission ID is not in the ready queue.
+    pub fn transmissions_after(
+        &self,
+        transmission_id: Option<TransmissionID<N>>,
+        limit: usize,
+    ) -> Option<Vec<(TransmissionID<N>, Transmission<N>)>> {
+        // Acquire the read lock.
+        let transmissions = self.transmissions.read();
+        // Determine the index of the first transmission to return.
+        let start = match transmission_id {
+            Some(transmission_id) => transmissions.get_index_of(&transmission_id)? + 1,
+            None => 0,
+        };
+        // Clone only the transmissions in the range.
+        let end = transmissions.len().min(start.saturating_add(limit));
+        Some((start..end).filter_map(|index| transmissions.get_index(index)).map(|(id, t)| (*id, t.clone())).collect())
+    }
+
     /// Returns the solutions in the ready queue.
     pub fn solutions(&self) -> impl '_ + Iterator<Item = (SolutionID<N>, Data<Solution<N>>)> {
         self.transmissions.read().clone().into_iter().filter_map(|(id, transmission)| match (id, transmission) {
@@ -111,12 +143,36 @@ impl<N: Network> Ready<N> {
     /// Returns `true` if the transmission is new, and was added to the ready queue.
     pub fn insert(&self, transmission_id: impl Into<TransmissionID<N>>, transmission: Transmission<N>) -> bool {
         let transmission_id = transmission_id.into();
+        // Acquire the write lock.
+        let mut transmissions = self.transmissions.write();
         // Insert the transmission ID.
-        let is_new = self.transmissions.write().insert(transmission_id, transmission).is_none();
+        let is_new = transmissions.insert(transmission_id, transmission).is_none();
+        // Update the counter, if the transmission is new.
+        if is_new {
+            if let Some(counter) = self.counter_for(&transmission_id) {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+        }
         // Return whether the transmission is new.
         is_new
     }
 
+    /// Removes the specified `transmission ID` from the ready queue, returning the transmission if it was present.
+    pub fn remove(&self, transmission_id: impl Into<TransmissionID<N>>) -> Option<Transmission<N>> {
+        let transmission_id = transmission_id.into();
+        // Acquire the write lock.
+        let mut transmissions = self.transmissions.write();
+        // Remove the transmission.
+        let transmission = transmissions.shift_remove(&transmission_id);
+        // Update the counter, if the transmission was present.
+        if transmission.is_some() {
+            if let Some(counter) = self.counter_for(&transmission_id) {
+                counter.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
+        transmission
+    }
+
     /// Removes up to the specified number of transmissions and returns them.
     pub fn drain(&self, num_transmissions: usize) -> IndexMap<TransmissionID<N>, Transmission<N>> {
         // Acquire the write lock.
@@ -124,7 +180,23 @@ impl<N: Network> Ready<N> {
         // Determine the number of transmissions to drain.
         let range = 0..transmissions.len().min(num_transmissions);
         // Drain the transmission IDs.
-        transmissions.drain(range).collect::<IndexMap<_, _>>()
+        let drained = transmissions.drain(range).collect::<IndexMap<_, _>>();
+        // Update the counters.
+        for transmission_id in drained.keys() {
+            if let Some(counter) = self.counter_for(transmission_id) {
+                counter.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
+        drained
+    }
+
+    /// Returns the counter for the type of the given transmission ID, if it is counted.
+    fn counter_for(&self, transmission_id: &TransmissionID<N>) -> Option<&AtomicUsize> {
+        match transmission_id {
+            TransmissionID::Solution(..) => Some(&self.num_solutions),
+            TransmissionID::Transaction(..) => Some(&self.num_transactions),
+            TransmissionID::Ratification => None,
+        }
     }
 }
 
@@ -164,6 +236,8 @@ mod tests {
 
         // Check the number of transmissions.
         assert_eq!(ready.num_transmissions(), 3);
+        assert_eq!(ready.num_solutions(), 3);
+        assert_eq!(ready.num_transactions(), 0);
 
         // Check the transmission IDs.
         let transmission_ids = vec![solution_id_1, solution_id_2, solution_id_3].into_iter().collect::<IndexSet<_>>();
@@ -185,6 +259,7 @@ mod tests {
 
         // Check the number of transmissions.
         assert!(ready.is_empty());
+        assert_eq!(ready.num_solutions(), 0);
         // Check the transmission IDs.
         assert_eq!(ready.transmission_ids(), IndexSet::new());
 
@@ -222,5 +297,65 @@ mod tests {
 
         // Check the number of transmissions.
         assert_eq!(ready.num_transmissions(), 1);
+        assert_eq!(ready.num_solutions(), 1);
+    }
+
+    #[test]
+    fn test_ready_remove() {
+        let rng = &mut TestRng::default();
+
+        // Sample random fake bytes.
+        let data = |rng: &mut TestRng| Data::Buffer(Bytes::from((0..512).map(|_| rng.gen::<u8>()).collect::<Vec<_>>()));
+
+        // Initialize the ready queue.
+        let ready = Ready::<CurrentNetwork>::new();
+
+        // Initialize the solution IDs.
+        let solution_id_1 = TransmissionID::Solution(rng.gen::<u64>().into());
+        let solution_id_2 = TransmissionID::Solution(rng.gen::<u64>().into());
+
+        // Initialize the solutions.
+        let solution_1 = Transmission::Solution(data(rng));
+        let solution_2 = Transmission::Solution(data(rng));
+
+        // Insert the solution IDs.
+        assert!(ready.insert(solution_id_1, solution_1.clone()));
+        assert!(ready.insert(solution_id_2, solution_2.clone()));
+
+        // Remove the first solution ID.
+        assert_eq!(ready.remove(solution_id_1), Some(solution_1));
+        // Removing it again returns nothing.
+        assert_eq!(ready.remove(solution_id_1), None);
+
+        // Check that only the second solution remains.
+        assert_eq!(ready.num_transmissions(), 1);
+        assert_eq!(ready.num_solutions(), 1);
+        assert!(!ready.contains(solution_id_1));
+        assert_eq!(ready.get(solution_id_2), Some(solution_2));
+    }
+
+    #[test]
+    fn test_ready_transmissions_after() {
+        let rng = &mut TestRng::default();
+
+        // Sample random fake bytes.
+        let data = |rng: &mut TestRng| Data::Buffer(Bytes::from((0..512).map(|_| rng.gen::<u8>()).collect::<Vec<_>>()));
+
+        // Initialize the ready queue, with three solutions.
+        let ready = Ready::<CurrentNetwork>::new();
+        let solutions = (0..3)
+            .map(|_| (TransmissionID::Solution(rng.gen::<u64>().into()), Transmission::Solution(data(rng))))
+            .collect::<Vec<_>>();
+        for (solution_id, solution) in &solutions {
+            assert!(ready.insert(*solution_id, solution.clone()));
+        }
+
+        // Check the transmissions from the beginning, and after each transmission.
+        assert_eq!(ready.transmissions_after(None, 2), Some(solutions[..2].to_vec()));
+        assert_eq!(ready.transmissions_after(Some(solutions[0].0), 5), Some(solutions[1..].to_vec()));
+        assert_eq!(ready.transmissions_after(Some(solutions[2].0), 5), Some(vec![]));
+        // Check that an unknown transmission ID is reported.
+        let solution_id_unknown = TransmissionID::Solution(rng.gen::<u64>().into());
+        assert_eq!(ready.transmissions_after(Some(solution_id_unknown), 5), None);
     }
 }
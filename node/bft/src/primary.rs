@@ -245,6 +245,18 @@ impl<N: Network> Primary<N> {
     pub fn num_unconfirmed_transactions(&self) -> usize {
         self.workers.iter().map(|worker| worker.num_transactions()).sum()
     }
+
+    /// Returns `true` if the transmission ID exists in the ready queue, proposed batch, storage, or ledger.
+    pub fn contains_transmission(&self, transmission_id: impl Into<TransmissionID<N>>) -> bool {
+        let transmission_id = transmission_id.into();
+        // Only the assigned worker may hold the transmission, so a single lookup suffices.
+        match assign_to_worker(transmission_id, self.num_workers()) {
+            Ok(worker_id) => {
+                self.workers.get(worker_id as usize).map_or(false, |w| w.contains_transmission(transmission_id))
+            }
+            Err(_) => false,
+        }
+    }
 }
 
 impl<N: Network> Primary<N> {
@@ -258,6 +270,43 @@ impl<N: Network> Primary<N> {
         self.workers.iter().flat_map(|worker| worker.transmissions())
     }
 
+    /// Returns up to `limit` unconfirmed transmissions after the given transmission ID, or from the beginning
+    /// if there is none, ordered by worker, then by insertion into the worker's ready queue.
+    /// Returns `None` if the given transmission ID is not in the ready queue of its worker.
+    pub fn unconfirmed_transmissions_after(
+        &self,
+        transmission_id: Option<TransmissionID<N>>,
+        limit: usize,
+    ) -> Option<Vec<(TransmissionID<N>, Transmission<N>)>> {
+        // Start from the transmission in its assigned worker, if one is given.
+        let (next_worker_id, mut transmissions) = match transmission_id {
+            Some(transmission_id) => {
+                let worker_id = assign_to_worker(transmission_id, self.num_workers()).ok()? as usize;
+                (worker_id + 1, self.workers.get(worker_id)?.transmissions_after(Some(transmission_id), limit)?)
+            }
+            None => (0, Vec::new()),
+        };
+        // Fill the remainder from the following workers.
+        for worker in self.workers.iter().skip(next_worker_id) {
+            if transmissions.len() >= limit {
+                break;
+            }
+            transmissions.extend(worker.transmissions_after(None, limit - transmissions.len()).unwrap_or_default());
+        }
+        Some(transmissions)
+    }
+
+    /// Returns the unconfirmed transmission for the given transmission ID, if it exists in the ready queue.
+    pub fn get_unconfirmed_transmission(
+        &self,
+        transmission_id: impl Into<TransmissionID<N>>,
+    ) -> Option<Transmission<N>> {
+        let transmission_id = transmission_id.into();
+        // Only the assigned worker may hold the transmission, so a single lookup suffices.
+        let worker_id = assign_to_worker(transmission_id, self.num_workers()).ok()?;
+        self.workers.get(worker_id as usize)?.get_ready_transmission(transmission_id)
+    }
+
     /// Returns the unconfirmed solutions.
     pub fn unconfirmed_solutions(&self) -> impl '_ + Iterator<Item = (SolutionID<N>, Data<Solution<N>>)> {
         self.workers.iter().flat_map(|worker| worker.solutions())
@@ -267,6 +316,17 @@ impl<N: Network> Primary<N> {
     pub fn unconfirmed_transactions(&self) -> impl '_ + Iterator<Item = (N::TransactionID, Data<Transaction<N>>)> {
         self.workers.iter().flat_map(|worker| worker.transactions())
     }
+
+    /// Removes all the transmissions from the ready queues of the workers, and returns them.
+    pub fn drain_unconfirmed_transmissions(&self) -> IndexMap<TransmissionID<N>, Transmission<N>> {
+        self.workers.iter().flat_map(|worker| worker.drain(worker.num_transmissions())).collect()
+    }
+
+    /// Removes the given transaction from the ready queues of the workers.
+    /// Returns `true` if the transaction was found and removed.
+    pub fn remove_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> bool {
+        self.workers.iter().fold(false, |is_removed, worker| worker.remove(transaction_id) || is_removed)
+    }
 }
 
 impl<N: Network> Primary<N> {
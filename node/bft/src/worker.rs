@@ -139,6 +139,16 @@ impl<N: Network> Worker<N> {
         self.ready.transmissions()
     }
 
+    /// Returns up to `limit` transmissions in the ready queue after the given transmission ID,
+    /// or from the beginning if there is none. Returns `None` if the given transmission ID is not in the ready queue.
+    pub fn transmissions_after(
+        &self,
+        transmission_id: Option<TransmissionID<N>>,
+        limit: usize,
+    ) -> Option<Vec<(TransmissionID<N>, Transmission<N>)>> {
+        self.ready.transmissions_after(transmission_id, limit)
+    }
+
     /// Returns the solutions in the ready queue.
     pub fn solutions(&self) -> impl '_ + Iterator<Item = (SolutionID<N>, Data<Solution<N>>)> {
         self.ready.solutions()
@@ -148,6 +158,11 @@ impl<N: Network> Worker<N> {
     pub fn transactions(&self) -> impl '_ + Iterator<Item = (N::TransactionID, Data<Transaction<N>>)> {
         self.ready.transactions()
     }
+
+    /// Returns the transmission for the given transmission ID, if it exists in the ready queue.
+    pub fn get_ready_transmission(&self, transmission_id: impl Into<TransmissionID<N>>) -> Option<Transmission<N>> {
+        self.ready.get(transmission_id)
+    }
 }
 
 impl<N: Network> Worker<N> {
@@ -216,6 +231,12 @@ impl<N: Network> Worker<N> {
         false
     }
 
+    /// Removes the specified transmission from the ready queue, returning `true` if it was present.
+    /// Note: Transmissions that are already proposed or certified are not affected.
+    pub(crate) fn remove(&self, transmission_id: impl Into<TransmissionID<N>>) -> bool {
+        self.ready.remove(transmission_id).is_some()
+    }
+
     /// Broadcasts a worker ping event.
     pub(crate) fn broadcast_ping(&self) {
         // Retrieve the transmission IDs.
@@ -506,6 +527,7 @@ mod tests {
         impl<N: Network> LedgerService<N> for Ledger<N> {
             fn latest_round(&self) -> u64;
             fn latest_block_height(&self) -> u32;
+            fn latest_block_hash(&self) -> N::BlockHash;
             fn latest_block(&self) -> Block<N>;
             fn latest_leader(&self) -> Option<(u64, Address<N>)>;
             fn update_latest_leader(&self, round: u64, leader: Address<N>);
@@ -515,7 +537,9 @@ mod tests {
             fn get_block_round(&self, height: u32) -> Result<u64>;
             fn get_block(&self, height: u32) -> Result<Block<N>>;
             fn get_blocks(&self, heights: Range<u32>) -> Result<Vec<Block<N>>>;
+            fn find_block_hash(&self, transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>>;
             fn get_solution(&self, solution_id: &SolutionID<N>) -> Result<Solution<N>>;
+            fn get_proof_target(&self, solution: &Solution<N>) -> Result<u64>;
             fn get_unconfirmed_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>>;
             fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<BatchCertificate<N>>;
             fn current_committee(&self) -> Result<Committee<N>>;
@@ -545,6 +569,7 @@ mod tests {
                 transmissions: IndexMap<TransmissionID<N>, Transmission<N>>,
             ) -> Result<Block<N>>;
             fn advance_to_next_block(&self, block: &Block<N>) -> Result<()>;
+            fn fork_at(&self, height: u32) -> Result<Arc<dyn LedgerService<N>>>;
         }
     }
 
@@ -879,6 +904,72 @@ mod tests {
         assert!(worker.ready.contains(transmission_id));
     }
 
+    #[tokio::test]
+    async fn test_contains_transmission_unconfirmed() {
+        let mut rng = &mut TestRng::default();
+        // Sample a committee.
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        let committee_clone = committee.clone();
+        // Setup the mock gateway and ledger.
+        let gateway = MockGateway::default();
+        let mut mock_ledger = MockLedger::default();
+        mock_ledger.expect_current_committee().returning(move || Ok(committee.clone()));
+        mock_ledger.expect_get_committee_lookback_for_round().returning(move |_| Ok(committee_clone.clone()));
+        mock_ledger.expect_contains_transmission().returning(|_| Ok(false));
+        mock_ledger.expect_check_transaction_basic().returning(|_, _| Ok(()));
+        let ledger: Arc<dyn LedgerService<CurrentNetwork>> = Arc::new(mock_ledger);
+        // Initialize the storage.
+        let storage = Storage::<CurrentNetwork>::new(ledger.clone(), Arc::new(BFTMemoryService::new()), 1);
+
+        // Create the Worker.
+        let worker = Worker::new(0, Arc::new(gateway), storage, ledger, Default::default()).unwrap();
+        let transaction_id: <CurrentNetwork as Network>::TransactionID = Field::<CurrentNetwork>::rand(&mut rng).into();
+        let transaction = Data::Buffer(Bytes::from((0..512).map(|_| rng.gen::<u8>()).collect::<Vec<_>>()));
+
+        // Ensure the transaction is not yet known.
+        assert!(!worker.contains_transmission(&transaction_id));
+        // Process the unconfirmed transaction.
+        assert!(worker.process_unconfirmed_transaction(transaction_id, transaction.clone()).await.is_ok());
+        // Ensure the transaction is now known.
+        assert!(worker.contains_transmission(&transaction_id));
+        // Ensure resubmitting the transaction is rejected.
+        assert!(worker.process_unconfirmed_transaction(transaction_id, transaction).await.is_err());
+        assert_eq!(worker.num_transactions(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_contains_transmission_in_ledger() {
+        let mut rng = &mut TestRng::default();
+        // Sample a committee.
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        let committee_clone = committee.clone();
+        // Sample a transaction ID that is confirmed in the ledger.
+        let transaction_id: <CurrentNetwork as Network>::TransactionID = Field::<CurrentNetwork>::rand(&mut rng).into();
+        let confirmed_id = TransmissionID::Transaction(transaction_id);
+        // Setup the mock gateway and ledger.
+        let gateway = MockGateway::default();
+        let mut mock_ledger = MockLedger::default();
+        mock_ledger.expect_current_committee().returning(move || Ok(committee.clone()));
+        mock_ledger.expect_get_committee_lookback_for_round().returning(move |_| Ok(committee_clone.clone()));
+        mock_ledger.expect_contains_transmission().returning(move |id| Ok(*id == confirmed_id));
+        let ledger: Arc<dyn LedgerService<CurrentNetwork>> = Arc::new(mock_ledger);
+        // Initialize the storage.
+        let storage = Storage::<CurrentNetwork>::new(ledger.clone(), Arc::new(BFTMemoryService::new()), 1);
+
+        // Create the Worker.
+        let worker = Worker::new(0, Arc::new(gateway), storage, ledger, Default::default()).unwrap();
+        let transaction = Data::Buffer(Bytes::from((0..512).map(|_| rng.gen::<u8>()).collect::<Vec<_>>()));
+
+        // Ensure the confirmed transaction is known.
+        assert!(worker.contains_transmission(&transaction_id));
+        // Ensure the confirmed transaction is rejected.
+        assert!(worker.process_unconfirmed_transaction(transaction_id, transaction).await.is_err());
+        assert_eq!(worker.num_transactions(), 0);
+        // Ensure an unrelated transaction is not known.
+        let other_id: <CurrentNetwork as Network>::TransactionID = Field::<CurrentNetwork>::rand(&mut rng).into();
+        assert!(!worker.contains_transmission(&other_id));
+    }
+
     #[tokio::test]
     async fn test_storage_gc_on_initialization() {
         let rng = &mut TestRng::default();
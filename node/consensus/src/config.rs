@@ -0,0 +1,232 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{MemoryPoolConfig, RateLimit};
+use snarkos_node_bft::helpers::MAX_CHANNEL_SIZE;
+use snarkvm::{
+    ledger::narwhal::BatchHeader,
+    prelude::{ensure, Network, Result},
+};
+
+use std::time::Duration;
+
+/// The minimum number of rounds retained in the Narwhal storage.
+/// Note: A leader is elected every even round, so the window must retain at least one leader certificate.
+pub const MIN_GC_ROUNDS: u64 = 2;
+/// The maximum number of rounds that may be configured for the Narwhal storage.
+/// Note: This bound keeps the round arithmetic in the storage from overflowing.
+pub const MAX_CONFIGURABLE_GC_ROUNDS: u64 = u64::MAX / 2;
+/// The default capacity of the new blocks channel.
+const DEFAULT_NEW_BLOCKS_CAPACITY: usize = 1 << 6;
+/// The default interval between sweeps for expired transactions.
+const DEFAULT_EXPIRY_SWEEP_INTERVAL: Duration = Duration::from_secs(10);
+/// The default age of the latest block, after which consensus is reported as degraded.
+const DEFAULT_MAX_BLOCK_AGE: Duration = Duration::from_secs(60);
+/// The default number of recent blocks used to estimate the block time.
+const DEFAULT_BLOCK_TIME_WINDOW: usize = 10;
+
+/// The configuration of a consensus instance.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConsensusConfig {
+    /// The number of rounds retained in the Narwhal storage before garbage collection.
+    /// If `None`, the protocol maximum of `BatchHeader::MAX_GC_ROUNDS` is used.
+    ///
+    /// A smaller window lowers the memory and disk usage of the node, but a node that falls behind
+    /// by more than the window must sync from blocks instead of certificates. A larger window retains
+    /// more certificates for peers to sync from, at the cost of memory and disk usage.
+    pub gc_rounds: Option<u64>,
+    /// The number of confirmed blocks buffered for each subscriber before the slowest one starts lagging.
+    /// If `None`, a default capacity of 64 blocks is used.
+    pub new_blocks_capacity: Option<usize>,
+    /// The number of committed subdags buffered between the BFT and consensus, before the BFT waits on consensus.
+    /// If `None`, a default capacity of 8192 subdags is used.
+    pub consensus_channel_capacity: Option<usize>,
+    /// The time to wait for the in-flight subdags to be advanced into blocks on shutdown, before the tasks are aborted.
+    /// If `None`, the tasks are aborted without waiting.
+    pub shutdown_timeout: Option<Duration>,
+    /// Whether the memory pool is saved to the ledger directory on shutdown, and reloaded on startup.
+    pub persist_memory_pool: bool,
+    /// The minimum fee of a transaction, in microcredits,
+    /// which may be adjusted at runtime via `Consensus::set_min_fee`.
+    pub min_fee: u64,
+    /// The time a transaction may wait in the inbound queue before it is expired.
+    /// If `None`, transactions do not expire.
+    pub transaction_ttl: Option<Duration>,
+    /// The interval between sweeps for expired transactions.
+    /// If `None`, a default of 10 seconds is used.
+    pub expiry_sweep_interval: Option<Duration>,
+    /// The age of the latest block, after which the health check reports consensus as degraded.
+    /// If `None`, a default of 60 seconds is used.
+    pub max_block_age: Option<Duration>,
+    /// The number of recent blocks used to estimate the time of the next block.
+    /// If `None`, a default of 10 blocks is used.
+    pub block_time_window: Option<usize>,
+    /// The proof target, above the coinbase target, that the unconfirmed solutions must reach
+    /// before `Consensus::coinbase_ready` reports the coinbase target as met.
+    pub coinbase_target_margin: u128,
+    /// The proof target, below the coinbase target, that the unconfirmed solutions may fall to
+    /// after the coinbase target is met, before `Consensus::coinbase_ready` reports it as unmet.
+    pub coinbase_target_tolerance: u128,
+    /// The rate limit on the verified transactions submitted by each fee payer.
+    /// If `None`, transactions are not rate limited.
+    /// Note: Transactions with a private fee are never rate limited, as they do not reveal their fee payer.
+    pub per_address_rate: Option<RateLimit>,
+    /// The memory pool configuration.
+    pub memory_pool: MemoryPoolConfig,
+}
+
+impl ConsensusConfig {
+    /// Returns the number of rounds retained in the Narwhal storage.
+    pub fn gc_rounds<N: Network>(&self) -> u64 {
+        self.gc_rounds.unwrap_or(BatchHeader::<N>::MAX_GC_ROUNDS as u64)
+    }
+
+    /// Returns the time to wait for the in-flight subdags on shutdown.
+    pub fn shutdown_timeout(&self) -> Duration {
+        self.shutdown_timeout.unwrap_or(Duration::ZERO)
+    }
+
+    /// Returns the interval between sweeps for expired transactions.
+    pub fn expiry_sweep_interval(&self) -> Duration {
+        self.expiry_sweep_interval.unwrap_or(DEFAULT_EXPIRY_SWEEP_INTERVAL)
+    }
+
+    /// Returns the age of the latest block, after which consensus is reported as degraded.
+    pub fn max_block_age(&self) -> Duration {
+        self.max_block_age.unwrap_or(DEFAULT_MAX_BLOCK_AGE)
+    }
+
+    /// Returns the number of recent blocks used to estimate the time of the next block.
+    pub fn block_time_window(&self) -> usize {
+        self.block_time_window.unwrap_or(DEFAULT_BLOCK_TIME_WINDOW)
+    }
+
+    /// Returns the capacity of the new blocks channel.
+    pub fn new_blocks_capacity(&self) -> usize {
+        self.new_blocks_capacity.unwrap_or(DEFAULT_NEW_BLOCKS_CAPACITY)
+    }
+
+    /// Returns the capacity of the consensus channels.
+    pub fn consensus_channel_capacity(&self) -> usize {
+        self.consensus_channel_capacity.unwrap_or(MAX_CHANNEL_SIZE)
+    }
+
+    /// Ensures the garbage collection window, the channel capacities, and the memory pool limits are valid.
+    pub fn ensure_is_valid<N: Network>(&self) -> Result<()> {
+        let gc_rounds = self.gc_rounds::<N>();
+        ensure!(
+            gc_rounds >= MIN_GC_ROUNDS,
+            "The GC window of {gc_rounds} rounds is below the minimum of {MIN_GC_ROUNDS}"
+        );
+        ensure!(
+            gc_rounds <= MAX_CONFIGURABLE_GC_ROUNDS,
+            "The GC window of {gc_rounds} rounds is above the maximum of {MAX_CONFIGURABLE_GC_ROUNDS}"
+        );
+        ensure!(self.new_blocks_capacity() > 0, "The new blocks channel must have a non-zero capacity");
+        ensure!(self.consensus_channel_capacity() > 0, "The consensus channels must have a non-zero capacity");
+        ensure!(self.transaction_ttl != Some(Duration::ZERO), "The transaction TTL must be non-zero");
+        ensure!(!self.expiry_sweep_interval().is_zero(), "The expiry sweep interval must be non-zero");
+        ensure!(self.block_time_window() >= 2, "The block time window must include at least 2 blocks");
+        if let Some(rate_limit) = &self.per_address_rate {
+            rate_limit.ensure_is_valid()?;
+        }
+        self.memory_pool.ensure_is_valid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Consensus;
+    use snarkos_node_bft::helpers::init_consensus_channels_with_capacity;
+    use snarkos_node_bft_ledger_service::MockLedgerService;
+    use snarkos_node_bft_storage_service::BFTMemoryService;
+    use snarkvm::prelude::TestRng;
+
+    use std::sync::Arc;
+
+    type CurrentNetwork = snarkvm::prelude::MainnetV0;
+
+    #[test]
+    fn test_consensus_config() {
+        // Ensure the default configuration is valid, and uses the protocol maximum.
+        let config = ConsensusConfig::default();
+        assert!(config.ensure_is_valid::<CurrentNetwork>().is_ok());
+        assert_eq!(config.gc_rounds::<CurrentNetwork>(), BatchHeader::<CurrentNetwork>::MAX_GC_ROUNDS as u64);
+
+        // Ensure a window below the minimum is rejected.
+        let config = ConsensusConfig { gc_rounds: Some(MIN_GC_ROUNDS - 1), ..Default::default() };
+        assert!(config.ensure_is_valid::<CurrentNetwork>().is_err());
+        // Ensure a window at the minimum is accepted.
+        let config = ConsensusConfig { gc_rounds: Some(MIN_GC_ROUNDS), ..Default::default() };
+        assert!(config.ensure_is_valid::<CurrentNetwork>().is_ok());
+        // Ensure a window at the maximum is accepted, and one above it is rejected.
+        let config = ConsensusConfig { gc_rounds: Some(MAX_CONFIGURABLE_GC_ROUNDS), ..Default::default() };
+        assert!(config.ensure_is_valid::<CurrentNetwork>().is_ok());
+        let config = ConsensusConfig { gc_rounds: Some(MAX_CONFIGURABLE_GC_ROUNDS + 1), ..Default::default() };
+        assert!(config.ensure_is_valid::<CurrentNetwork>().is_err());
+
+        // Ensure a zero-capacity new blocks channel is rejected.
+        let config = ConsensusConfig { new_blocks_capacity: Some(0), ..Default::default() };
+        assert!(config.ensure_is_valid::<CurrentNetwork>().is_err());
+        // Ensure zero-capacity consensus channels are rejected.
+        let config = ConsensusConfig { consensus_channel_capacity: Some(0), ..Default::default() };
+        assert!(config.ensure_is_valid::<CurrentNetwork>().is_err());
+
+        // Ensure a zero TTL or sweep interval is rejected.
+        let config = ConsensusConfig { transaction_ttl: Some(Duration::ZERO), ..Default::default() };
+        assert!(config.ensure_is_valid::<CurrentNetwork>().is_err());
+        let config = ConsensusConfig { expiry_sweep_interval: Some(Duration::ZERO), ..Default::default() };
+        assert!(config.ensure_is_valid::<CurrentNetwork>().is_err());
+        // Ensure a block time window of fewer than 2 blocks is rejected.
+        let config = ConsensusConfig { block_time_window: Some(1), ..Default::default() };
+        assert!(config.ensure_is_valid::<CurrentNetwork>().is_err());
+        // Ensure a zero rate limit is rejected.
+        let config =
+            ConsensusConfig { per_address_rate: Some(RateLimit { burst: 0, per_second: 1 }), ..Default::default() };
+        assert!(config.ensure_is_valid::<CurrentNetwork>().is_err());
+    }
+
+    #[test]
+    fn test_custom_gc_rounds_in_storage() {
+        let rng = &mut TestRng::default();
+        // Sample a committee.
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        let ledger = Arc::new(MockLedgerService::new(committee));
+
+        // Initialize the storage with a custom window.
+        let config = ConsensusConfig { gc_rounds: Some(7), ..Default::default() };
+        let storage =
+            Consensus::<CurrentNetwork>::init_narwhal_storage(ledger, Arc::new(BFTMemoryService::new()), &config);
+        // Ensure the custom window is reflected in the storage.
+        assert_eq!(storage.max_gc_rounds(), 7);
+    }
+
+    #[test]
+    fn test_custom_consensus_channel_capacity() {
+        // Ensure the default capacity matches the default channels.
+        assert_eq!(ConsensusConfig::default().consensus_channel_capacity(), MAX_CHANNEL_SIZE);
+
+        // Initialize the channels with a custom capacity.
+        let config = ConsensusConfig { consensus_channel_capacity: Some(4), ..Default::default() };
+        let (sender, _receiver) =
+            init_consensus_channels_with_capacity::<CurrentNetwork>(config.consensus_channel_capacity());
+        // Ensure the channel accepts up to the capacity without blocking, and is full afterwards.
+        let permits = (0..4).map(|_| sender.tx_consensus_subdag.try_reserve().unwrap()).collect::<Vec<_>>();
+        assert!(sender.tx_consensus_subdag.try_reserve().is_err());
+        // Ensure the capacity is released once the queued items are dropped.
+        drop(permits);
+        assert_eq!(sender.tx_consensus_subdag.capacity(), 4);
+    }
+}
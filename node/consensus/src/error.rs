@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConsensusError {
+    #[error("Consensus is not running - the primary sender is not set")]
+    NotRunning,
+    #[error("Consensus is shutting down")]
+    ShuttingDown,
+    #[error("Invalid consensus configuration - {0}")]
+    InvalidConfig(String),
+    #[error("Transaction '{0}' already exists")]
+    TransactionAlreadyExists(String),
+    #[error("Solution '{0}' already exists")]
+    SolutionAlreadyExists(String),
+    #[error("Transaction is invalid - {0}")]
+    TransactionInvalid(String),
+    #[error("Solution is invalid - {0}")]
+    SolutionInvalid(String),
+    #[error("The memory pool is full")]
+    MemoryPoolFull,
+    #[error("The transaction fee of {actual} microcredits is below the minimum of {required} microcredits")]
+    FeeTooLow { required: u64, actual: u64 },
+    #[error("Transaction '{0}' does not pay enough to replace the conflicting transaction in the memory pool")]
+    ReplacementUnderpriced(String),
+    #[error("Transaction '{0}' was recently evicted from the memory pool")]
+    TransactionEvicted(String),
+    #[error("The primary did not accept the transaction in time")]
+    Backpressure,
+    #[error("The fee payer '{0}' exceeded the transaction rate limit")]
+    RateLimited(String),
+    #[error("Transaction '{0}' is not in the memory pool")]
+    TransactionNotInPool(String),
+    #[error("The page cursor '{0}' is not in the memory pool")]
+    CursorNotFound(String),
+    #[error("The page limit must be non-zero")]
+    ZeroPageLimit,
+    #[error("Timed out waiting for {0}")]
+    Timeout(String),
+    #[error("Block '{0}' was not found")]
+    BlockNotFound(String),
+    #[error(transparent)]
+    BlockInvalid(#[from] BlockValidationError),
+    #[error("Committee for round {0} was not found")]
+    CommitteeNotFound(u64),
+    #[error("Round {0} is before the genesis round, or beyond the committee lookback range of the current round")]
+    RoundOutOfRange(u64),
+    #[error("No leader is elected for odd round {0}")]
+    NoLeaderForRound(u64),
+    #[error("Invalid memory pool snapshot - {0}")]
+    InvalidSnapshot(String),
+    #[error("I/O error - {0}")]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    LedgerError(#[from] anyhow::Error),
+    #[error("BFT error - {0}")]
+    BftError(anyhow::Error),
+}
+
+impl ConsensusError {
+    /// Returns `true` if the error may not recur on a retry, such as a full memory pool or a busy primary.
+    pub const fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::NotRunning
+                | Self::ShuttingDown
+                | Self::MemoryPoolFull
+                | Self::TransactionEvicted(_)
+                | Self::Backpressure
+                | Self::RateLimited(_)
+                | Self::Timeout(_)
+        )
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum BlockValidationError {
+    #[error("Block {found} does not follow the latest block height {latest}")]
+    InvalidHeight { latest: u32, found: u32 },
+    #[error("Block {height} has previous hash '{found}', but the latest block hash is '{expected}'")]
+    InvalidPreviousHash { height: u32, expected: String, found: String },
+    #[error("Block {height} has timestamp {found}, but the latest block timestamp is {latest}")]
+    InvalidTimestamp { height: u32, latest: i64, found: i64 },
+    #[error("Block {height} is invalid - {reason}")]
+    InvalidBlock { height: u32, reason: String },
+}
+
+#[derive(Debug, Error)]
+pub enum AdvanceError {
+    #[error("The next block is invalid - {0}")]
+    InvalidBlock(String),
+    #[error("Failed to store the next block - {0}")]
+    Storage(String),
+}
+
+impl AdvanceError {
+    /// Returns `true` if the error may not recur on a retry, such as a storage failure.
+    pub const fn is_transient(&self) -> bool {
+        matches!(self, Self::Storage(_))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum CatchupError {
+    #[error("Block {height} is invalid, after applying {num_applied} blocks - {reason}")]
+    InvalidBlock { height: u32, num_applied: usize, reason: String },
+    #[error("Failed to store block {height}, after applying {num_applied} blocks - {reason}")]
+    Storage { height: u32, num_applied: usize, reason: String },
+    #[error("Block {height} is not a known checkpoint")]
+    UnknownCheckpoint { height: u32 },
+    #[error("Checkpoint at block {height} does not match - {reason}")]
+    CheckpointMismatch { height: u32, reason: String },
+}
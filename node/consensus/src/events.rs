@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm::{ledger::block::Block, prelude::Network};
+
+/// The reason an unconfirmed transaction was dropped from the memory pool.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DropReason {
+    /// The transaction was evicted from the full inbound queue.
+    Evicted,
+    /// The transaction was rejected by the primary.
+    Rejected(String),
+    /// The transaction was removed on request.
+    Removed,
+    /// The transaction was aborted from the block it was committed in.
+    Aborted,
+    /// The transaction waited in the inbound queue for longer than the configured TTL.
+    Expired,
+    /// The transaction spends an input that was spent by a confirmed transaction.
+    Conflicted,
+    /// The transaction was evicted by the operator.
+    ManuallyEvicted,
+}
+
+/// An event in the lifecycle of an unconfirmed transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionEvent<N: Network> {
+    /// The transaction was accepted into the memory pool.
+    Accepted(N::TransactionID),
+    /// The transaction was included in the block at the given height.
+    IncludedInBlock(N::TransactionID, u32),
+    /// The transaction was dropped from the memory pool.
+    Dropped(N::TransactionID, DropReason),
+    /// The first transaction was replaced in the memory pool by the second, conflicting transaction with a higher fee.
+    Replaced(N::TransactionID, N::TransactionID),
+}
+
+/// A summary of a block that consensus advanced the ledger to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BlockAdvanced {
+    /// The height of the block.
+    pub height: u32,
+    /// The round of the block.
+    pub round: u64,
+    /// The number of confirmed transactions in the block.
+    pub num_transactions: usize,
+    /// The number of solutions in the block.
+    pub num_solutions: usize,
+    /// Whether the block has a coinbase reward, which is the case if it contains any solutions.
+    pub has_coinbase: bool,
+}
+
+impl<N: Network> From<&Block<N>> for BlockAdvanced {
+    /// Summarizes the given block.
+    fn from(block: &Block<N>) -> Self {
+        let num_solutions = block.solutions().len();
+        Self {
+            height: block.height(),
+            round: block.round(),
+            num_transactions: block.transactions().len(),
+            num_solutions,
+            has_coinbase: num_solutions > 0,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm::{
+    ledger::block::{Header, TransactionsPath},
+    prelude::{Network, ToBits},
+};
+
+/// A proof that a transaction was included in a confirmed block, which is verifiable against the block header.
+#[derive(Clone, Debug)]
+pub struct InclusionProof<N: Network> {
+    /// The height of the block that includes the transaction.
+    block_height: u32,
+    /// The ID of the transaction.
+    transaction_id: N::TransactionID,
+    /// The Merkle path from the transaction ID to the transactions root of the block.
+    transactions_path: TransactionsPath<N>,
+}
+
+impl<N: Network> InclusionProof<N> {
+    /// Initializes a new inclusion proof.
+    pub const fn new(
+        block_height: u32,
+        transaction_id: N::TransactionID,
+        transactions_path: TransactionsPath<N>,
+    ) -> Self {
+        Self { block_height, transaction_id, transactions_path }
+    }
+
+    /// Returns the height of the block that includes the transaction.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the ID of the transaction.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the Merkle path from the transaction ID to the transactions root of the block.
+    pub const fn transactions_path(&self) -> &TransactionsPath<N> {
+        &self.transactions_path
+    }
+
+    /// Returns `true` if the proof is valid for the given block header.
+    pub fn verify(&self, header: &Header<N>) -> bool {
+        header.height() == self.block_height
+            && N::verify_merkle_path_bhp(
+                &self.transactions_path,
+                &header.transactions_root(),
+                &self.transaction_id.to_bits_le(),
+            )
+    }
+}
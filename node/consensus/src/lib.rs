@@ -17,26 +17,53 @@
 #[macro_use]
 extern crate tracing;
 
+mod config;
+pub use config::*;
+
+mod error;
+pub use error::*;
+
+mod events;
+pub use events::*;
+
+mod inclusion_proof;
+pub use inclusion_proof::*;
+
+mod memory_pool;
+pub use memory_pool::*;
+
+mod rate_limit;
+pub use rate_limit::*;
+
+mod status;
+pub use status::*;
+
+mod tasks;
+pub use tasks::*;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 use snarkos_account::Account;
 use snarkos_node_bft::{
     helpers::{
         fmt_id,
-        init_consensus_channels,
+        init_consensus_channels_with_capacity,
         ConsensusReceiver,
         PrimaryReceiver,
         PrimarySender,
         Storage as NarwhalStorage,
     },
-    spawn_blocking,
     Primary,
     BFT,
 };
 use snarkos_node_bft_ledger_service::LedgerService;
-use snarkos_node_bft_storage_service::BFTPersistentStorage;
+use snarkos_node_bft_storage_service::{BFTPersistentStorage, StorageService};
 use snarkvm::{
     ledger::{
-        block::Transaction,
-        narwhal::{BatchHeader, Data, Subdag, Transmission, TransmissionID},
+        block::{block_reward, coinbase_reward, Block, Transaction},
+        committee::Committee,
+        narwhal::{BatchCertificate, BatchHeader, Data, Subdag, Transmission, TransmissionID},
         puzzle::{Solution, SolutionID},
     },
     prelude::*,
@@ -47,40 +74,57 @@ use anyhow::Result;
 use colored::Colorize;
 use indexmap::IndexMap;
 use lru::LruCache;
-use parking_lot::Mutex;
-use std::{future::Future, net::SocketAddr, num::NonZeroUsize, sync::Arc};
-use tokio::{
-    sync::{oneshot, OnceCell},
-    task::JoinHandle,
+use parking_lot::{Mutex, RwLock};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    future::Future,
+    net::SocketAddr,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
+use tokio::sync::{broadcast, oneshot, watch, OnceCell};
+use tracing::{instrument, Instrument, Span};
 
-/// The capacity of the queue reserved for deployments.
-/// Note: This is an inbound queue capacity, not a Narwhal-enforced capacity.
-const CAPACITY_FOR_DEPLOYMENTS: usize = 1 << 10;
-/// The capacity of the queue reserved for executions.
-/// Note: This is an inbound queue capacity, not a Narwhal-enforced capacity.
-const CAPACITY_FOR_EXECUTIONS: usize = 1 << 10;
-/// The capacity of the queue reserved for solutions.
-/// Note: This is an inbound queue capacity, not a Narwhal-enforced capacity.
-const CAPACITY_FOR_SOLUTIONS: usize = 1 << 10;
 /// The **suggested** maximum number of deployments in each interval.
 /// Note: This is an inbound queue limit, not a Narwhal-enforced limit.
 const MAX_DEPLOYMENTS_PER_INTERVAL: usize = 1;
-
-/// Helper struct to track incoming transactions.
-struct TransactionsQueue<N: Network> {
-    pub deployments: LruCache<N::TransactionID, Transaction<N>>,
-    pub executions: LruCache<N::TransactionID, Transaction<N>>,
-}
-
-impl<N: Network> Default for TransactionsQueue<N> {
-    fn default() -> Self {
-        Self {
-            deployments: LruCache::new(NonZeroUsize::new(CAPACITY_FOR_DEPLOYMENTS).unwrap()),
-            executions: LruCache::new(NonZeroUsize::new(CAPACITY_FOR_EXECUTIONS).unwrap()),
-        }
-    }
-}
+/// The file name of the persisted memory pool, in the ledger directory.
+const MEMORY_POOL_FILE_NAME: &str = "memory_pool.snapshot";
+/// The capacity of the transaction events channel.
+const TRANSACTION_EVENTS_CAPACITY: usize = 1 << 10;
+/// The capacity of the block events channel.
+const BLOCK_EVENTS_CAPACITY: usize = 1 << 10;
+/// The capacity of the committed subdags channel.
+const COMMITTED_SUBDAGS_CAPACITY: usize = 1 << 6;
+/// The maximum number of attempts to advance to the next block, when the failures are transient.
+const MAX_ADVANCE_ATTEMPTS: u32 = 3;
+/// The delay before the first retry to advance to the next block, doubling on each subsequent retry.
+const ADVANCE_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+/// The tracing target for the inbound queues and the memory pool.
+/// Note: The targets are nested under the crate target, so `RUST_LOG=snarkos_node_consensus=debug` still applies.
+const MEMPOOL_TARGET: &str = "snarkos_node_consensus::mempool";
+/// The tracing target for the committed subdags from the BFT.
+const SUBDAG_TARGET: &str = "snarkos_node_consensus::subdag";
+/// The tracing target for advancing the ledger to the next block.
+const BLOCK_TARGET: &str = "snarkos_node_consensus::block";
+/// The estimated block time, when there are too few recent blocks to measure it.
+const DEFAULT_BLOCK_TIME: Duration = Duration::from_secs(10);
+/// The maximum number of submitters tracked by the rate limiter.
+const RATE_LIMITER_CAPACITY: usize = 1 << 16;
+/// The time during which a manually evicted transaction may not re-enter the memory pool.
+const EVICTION_COOLDOWN: Duration = Duration::from_secs(60);
+/// The maximum number of manually evicted transactions that are tracked for the cooldown.
+const MAX_EVICTED_TRANSACTIONS: usize = 1 << 10;
+/// The time during which a transaction that failed verification is rejected without being verified again.
+/// Note: The rejection is short-lived, as a transaction may become valid once the ledger advances.
+const INVALID_TRANSACTION_TTL: Duration = Duration::from_secs(60);
+/// The maximum number of transactions that failed verification, which are tracked for the TTL.
+const MAX_INVALID_TRANSACTIONS: usize = 1 << 12;
 
 #[derive(Clone)]
 pub struct Consensus<N: Network> {
@@ -94,12 +138,46 @@ pub struct Consensus<N: Network> {
     solutions_queue: Arc<Mutex<LruCache<SolutionID<N>, Solution<N>>>>,
     /// The unconfirmed transactions queue.
     transactions_queue: Arc<Mutex<TransactionsQueue<N>>>,
+    /// The proof targets of the pending solutions and of the latest block, for checking the coinbase target.
+    proof_target_cache: Arc<Mutex<ProofTargetCache<N>>>,
     /// The recently-seen unconfirmed solutions.
     seen_solutions: Arc<Mutex<LruCache<SolutionID<N>, ()>>>,
     /// The recently-seen unconfirmed transactions.
     seen_transactions: Arc<Mutex<LruCache<N::TransactionID, ()>>>,
-    /// The spawned handles.
-    handles: Arc<Mutex<Vec<JoinHandle<()>>>>,
+    /// The times at which transactions were manually evicted, to prevent their immediate re-entry.
+    evicted_transactions: Arc<Mutex<LruCache<N::TransactionID, Instant>>>,
+    /// The times at which transactions failed verification, with the reason, to reject their resubmissions cheaply.
+    invalid_transactions: Arc<Mutex<LruCache<N::TransactionID, (Instant, String)>>>,
+    /// The token buckets of the recent transaction submitters, by fee payer, if rate limiting is enabled.
+    rate_limiter: Arc<Mutex<LruCache<Address<N>, TokenBucket>>>,
+    /// The consensus configuration.
+    config: ConsensusConfig,
+    /// The minimum fee of a transaction, in microcredits.
+    min_fee: Arc<AtomicU64>,
+    /// The path of the persisted memory pool, if persistence is enabled.
+    memory_pool_path: Option<PathBuf>,
+    /// The timestamps of the most recent blocks, used to estimate the block time.
+    block_timestamps: Arc<Mutex<VecDeque<SystemTime>>>,
+    /// The known good block hashes, by height, from which catchup may start.
+    checkpoints: Arc<RwLock<HashMap<u32, N::BlockHash>>>,
+    /// The sender for the transaction events.
+    transaction_events: broadcast::Sender<TransactionEvent<N>>,
+    /// The sender for the confirmed blocks.
+    new_blocks: Arc<broadcast::Sender<Block<N>>>,
+    /// The sender for the block events.
+    block_events: broadcast::Sender<BlockAdvanced>,
+    /// The sender for the transactions accepted into the memory pool.
+    new_transactions: broadcast::Sender<Transaction<N>>,
+    /// The sender for the committed subdags and their transmissions.
+    committed_subdags: broadcast::Sender<(Subdag<N>, IndexMap<TransmissionID<N>, Transmission<N>>)>,
+    /// The sender for whether the coinbase target is met.
+    coinbase_ready: Arc<watch::Sender<bool>>,
+    /// The number of subdags that are being advanced into blocks.
+    num_in_flight_subdags: Arc<AtomicUsize>,
+    /// Indicates whether consensus is shutting down.
+    is_shutting_down: Arc<AtomicBool>,
+    /// The spawned tasks.
+    tasks: Arc<TaskRegistry>,
 }
 
 impl<N: Network> Consensus<N> {
@@ -110,43 +188,159 @@ impl<N: Network> Consensus<N> {
         ip: Option<SocketAddr>,
         trusted_validators: &[SocketAddr],
         storage_mode: StorageMode,
-    ) -> Result<Self> {
+        config: Option<ConsensusConfig>,
+    ) -> Result<Self, ConsensusError> {
+        // Initialize the consensus configuration.
+        let config = config.unwrap_or_default();
+        config.ensure_is_valid::<N>().map_err(|e| ConsensusError::InvalidConfig(e.to_string()))?;
+        // Initialize the Narwhal transmissions.
+        let transmissions =
+            Arc::new(BFTPersistentStorage::open(storage_mode.clone()).map_err(ConsensusError::BftError)?);
+        // Initialize the consensus with the transmissions.
+        Self::with_transmissions(account, ledger, transmissions, ip, trusted_validators, storage_mode, config)
+    }
+
+    /// Initializes a new instance of consensus, using the given Narwhal transmissions and a validated configuration.
+    fn with_transmissions(
+        account: Account<N>,
+        ledger: Arc<dyn LedgerService<N>>,
+        transmissions: Arc<dyn StorageService<N>>,
+        ip: Option<SocketAddr>,
+        trusted_validators: &[SocketAddr],
+        storage_mode: StorageMode,
+        config: ConsensusConfig,
+    ) -> Result<Self, ConsensusError> {
         // Recover the development ID, if it is present.
         let dev = match storage_mode {
             StorageMode::Development(id) => Some(id),
             StorageMode::Production | StorageMode::Custom(..) => None,
         };
-        // Initialize the Narwhal transmissions.
-        let transmissions = Arc::new(BFTPersistentStorage::open(storage_mode)?);
+        // Determine the path of the persisted memory pool, if persistence is enabled.
+        let memory_pool_path = config
+            .persist_memory_pool
+            .then(|| aleo_std::aleo_ledger_dir(N::ID, storage_mode).join(MEMORY_POOL_FILE_NAME));
         // Initialize the Narwhal storage.
-        let storage = NarwhalStorage::new(ledger.clone(), transmissions, BatchHeader::<N>::MAX_GC_ROUNDS as u64);
+        let storage = Self::init_narwhal_storage(ledger.clone(), transmissions, &config);
         // Initialize the BFT.
-        let bft = BFT::new(account, storage, ledger.clone(), ip, trusted_validators, dev)?;
+        let bft = BFT::new(account, storage, ledger.clone(), ip, trusted_validators, dev)
+            .map_err(ConsensusError::BftError)?;
         // Return the consensus.
         Ok(Self {
             ledger,
             bft,
             primary_sender: Default::default(),
-            solutions_queue: Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(CAPACITY_FOR_SOLUTIONS).unwrap()))),
-            transactions_queue: Default::default(),
+            solutions_queue: Arc::new(Mutex::new(LruCache::new(
+                NonZeroUsize::new(config.memory_pool.max_solutions).unwrap(),
+            ))),
+            transactions_queue: Arc::new(Mutex::new(TransactionsQueue::new(config.memory_pool.eviction_policy))),
+            proof_target_cache: Default::default(),
             seen_solutions: Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(1 << 16).unwrap()))),
             seen_transactions: Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(1 << 16).unwrap()))),
-            handles: Default::default(),
+            evicted_transactions: Arc::new(Mutex::new(LruCache::new(
+                NonZeroUsize::new(MAX_EVICTED_TRANSACTIONS).unwrap(),
+            ))),
+            invalid_transactions: Arc::new(Mutex::new(LruCache::new(
+                NonZeroUsize::new(MAX_INVALID_TRANSACTIONS).unwrap(),
+            ))),
+            rate_limiter: Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(RATE_LIMITER_CAPACITY).unwrap()))),
+            config,
+            min_fee: Arc::new(AtomicU64::new(config.min_fee)),
+            memory_pool_path,
+            block_timestamps: Default::default(),
+            checkpoints: Default::default(),
+            transaction_events: broadcast::channel(TRANSACTION_EVENTS_CAPACITY).0,
+            new_blocks: Arc::new(broadcast::channel(config.new_blocks_capacity()).0),
+            block_events: broadcast::channel(BLOCK_EVENTS_CAPACITY).0,
+            new_transactions: broadcast::channel(config.memory_pool.broadcast_capacity).0,
+            committed_subdags: broadcast::channel(COMMITTED_SUBDAGS_CAPACITY).0,
+            coinbase_ready: Arc::new(watch::channel(false).0),
+            num_in_flight_subdags: Default::default(),
+            is_shutting_down: Default::default(),
+            tasks: Default::default(),
         })
     }
 
+    /// Returns a fork of this consensus instance, which shares the ledger up to and including the given block height,
+    /// and starts with a fresh BFT state and an empty memory pool. Writes to either fork after the given height do not
+    /// affect the other.
+    ///
+    /// Note: This requires the ledger service to support snapshots (see [`LedgerService::fork_at`]),
+    /// and returns an error if the underlying consensus storage does not support them.
+    #[cfg(test)]
+    pub fn fork_at(&self, height: u32) -> Result<Self, ConsensusError> {
+        // Ensure the given height is in the ledger.
+        let latest_height = self.ledger.latest_block_height();
+        if height > latest_height {
+            return Err(anyhow!("Cannot fork at block {height}, above the latest block {latest_height}").into());
+        }
+        // Fork the ledger.
+        let ledger = match self.ledger.fork_at(height) {
+            Ok(ledger) => ledger,
+            Err(e) => return Err(anyhow!("Failed to fork the ledger at block {height} - {e}").into()),
+        };
+        // Initialize the fork, with in-memory transmissions and without a persisted memory pool.
+        let account = self.bft.primary().gateway().account().clone();
+        let transmissions = Arc::new(snarkos_node_bft_storage_service::BFTMemoryService::new());
+        let config = ConsensusConfig { persist_memory_pool: false, ..self.config };
+        Self::with_transmissions(account, ledger, transmissions, None, &[], StorageMode::Production, config)
+    }
+
+    /// Initializes the Narwhal storage with the configured garbage collection window.
+    fn init_narwhal_storage(
+        ledger: Arc<dyn LedgerService<N>>,
+        transmissions: Arc<dyn StorageService<N>>,
+        config: &ConsensusConfig,
+    ) -> NarwhalStorage<N> {
+        NarwhalStorage::new(ledger, transmissions, config.gc_rounds::<N>())
+    }
+
+    /// Records the fields that are common to the spans of the public methods, on the current span.
+    /// Note: The fields are only computed if the span is enabled.
+    fn record_span_fields(&self) {
+        let span = Span::current();
+        if !span.is_disabled() {
+            span.record("latest_height", self.ledger.latest_block_height());
+            span.record("num_transmissions", self.num_unconfirmed_transmissions());
+        }
+    }
+
     /// Run the consensus instance.
-    pub async fn run(&mut self, primary_sender: PrimarySender<N>, primary_receiver: PrimaryReceiver<N>) -> Result<()> {
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions))]
+    pub async fn run(
+        &mut self,
+        primary_sender: PrimarySender<N>,
+        primary_receiver: PrimaryReceiver<N>,
+    ) -> Result<(), ConsensusError> {
+        self.record_span_fields();
         info!("Starting the consensus instance...");
         // Set the primary sender.
         self.primary_sender.set(primary_sender.clone()).expect("Primary sender already set");
 
         // First, initialize the consensus channels.
-        let (consensus_sender, consensus_receiver) = init_consensus_channels();
+        let (consensus_sender, consensus_receiver) =
+            init_consensus_channels_with_capacity(self.config.consensus_channel_capacity());
         // Then, start the consensus handlers.
         self.start_handlers(consensus_receiver);
         // Lastly, the consensus.
-        self.bft.run(Some(consensus_sender), primary_sender, primary_receiver).await?;
+        self.bft
+            .run(Some(consensus_sender), primary_sender, primary_receiver)
+            .await
+            .map_err(ConsensusError::BftError)?;
+        // Reload the persisted memory pool, if persistence is enabled.
+        if let Some(path) = &self.memory_pool_path {
+            match self.load_from(path).await {
+                Ok(num_loaded) => {
+                    info!(
+                        target: MEMPOOL_TARGET,
+                        "Reloaded {num_loaded} unconfirmed transmission(s) from '{}'",
+                        path.display()
+                    )
+                }
+                Err(e) => {
+                    warn!(target: MEMPOOL_TARGET, "Failed to reload the memory pool from '{}' - {e}", path.display())
+                }
+            }
+        }
         Ok(())
     }
 
@@ -160,9 +354,295 @@ impl<N: Network> Consensus<N> {
         &self.bft
     }
 
-    /// Returns the primary sender.
-    pub fn primary_sender(&self) -> &PrimarySender<N> {
-        self.primary_sender.get().expect("Primary sender not set")
+    /// Returns the maximum number of unconfirmed transactions, across the inbound queue and the memory pool.
+    pub const fn max_unconfirmed_transactions(&self) -> usize {
+        self.config.memory_pool.max_transactions
+    }
+
+    /// Returns the latest block hash, without retrieving the latest block.
+    pub fn latest_block_hash(&self) -> N::BlockHash {
+        self.ledger.latest_block_hash()
+    }
+
+    /// Returns the confirmed block at the given height.
+    pub fn get_block(&self, height: u32) -> Result<Block<N>, ConsensusError> {
+        self.ledger.get_block(height).map_err(|_| ConsensusError::BlockNotFound(height.to_string()))
+    }
+
+    /// Returns the confirmed block with the given hash.
+    pub fn get_block_by_hash(&self, hash: &N::BlockHash) -> Result<Block<N>, ConsensusError> {
+        let height = self.ledger.get_block_height(hash).map_err(|_| ConsensusError::BlockNotFound(hash.to_string()))?;
+        self.get_block(height)
+    }
+
+    /// Returns a proof that the given transaction is included in a confirmed block, if it is.
+    /// Note: Only transactions are committed to in the block header, so solutions and ratifications return `None`.
+    pub fn inclusion_proof(&self, transmission_id: TransmissionID<N>) -> Option<InclusionProof<N>> {
+        let TransmissionID::Transaction(transaction_id) = transmission_id else {
+            return None;
+        };
+        // Retrieve the block that includes the transaction.
+        let block_hash = self.ledger.find_block_hash(&transaction_id).ok().flatten()?;
+        let block = self.get_block_by_hash(&block_hash).ok()?;
+        // Compute the Merkle path of the transaction to the transactions root.
+        let transactions_path = block.transactions().to_path(transaction_id).ok()?;
+        Some(InclusionProof::new(block.height(), transaction_id, transactions_path))
+    }
+
+    /// Returns the certificate for the given certificate ID, from the Narwhal storage,
+    /// or from the ledger if the certificate was committed and has since been garbage collected.
+    pub fn get_certificate(&self, certificate_id: &Field<N>) -> Option<BatchCertificate<N>> {
+        // Check the Narwhal storage, then the ledger.
+        let certificate = self.bft.storage().get_certificate(*certificate_id);
+        certificate.or_else(|| self.ledger.get_batch_certificate(certificate_id).ok())
+    }
+
+    /// Returns the committee for the current round of the BFT.
+    pub fn get_committee(&self) -> Result<Committee<N>, ConsensusError> {
+        self.get_committee_for_round(self.bft.storage().current_round())
+    }
+
+    /// Returns the committee for the given round.
+    /// Returns `ConsensusError::CommitteeNotFound` if the round has been garbage collected,
+    /// or is unknown to the ledger.
+    pub fn get_committee_for_round(&self, round: u64) -> Result<Committee<N>, ConsensusError> {
+        // Ensure the round has not been garbage collected.
+        let gc_round = self.bft.storage().gc_round();
+        if gc_round > 0 && round <= gc_round {
+            return Err(ConsensusError::CommitteeNotFound(round));
+        }
+        self.ledger.get_committee_for_round(round).map_err(|_| ConsensusError::CommitteeNotFound(round))
+    }
+
+    /// Returns the consensus configuration.
+    pub const fn config(&self) -> &ConsensusConfig {
+        &self.config
+    }
+
+    /// Returns the latest round committed by the BFT, which may be ahead of the latest round in the ledger.
+    pub fn latest_committed_round(&self) -> u64 {
+        self.bft.last_committed_round()
+    }
+
+    /// Returns the leader of the given round, as elected by the BFT from the committee lookback.
+    ///
+    /// Returns `ConsensusError::RoundOutOfRange` for the genesis round, or for rounds beyond the committee
+    /// lookback range of the current round, whose committee is not yet determined.
+    /// Returns `ConsensusError::NoLeaderForRound` for odd rounds, as leaders are only elected for even rounds.
+    pub fn get_round_leader(&self, round: u64) -> Result<Address<N>, ConsensusError> {
+        // Ensure the round is within range.
+        let max_round = self.bft.storage().current_round().saturating_add(Committee::<N>::COMMITTEE_LOOKBACK_RANGE);
+        if round == 0 || round > max_round {
+            return Err(ConsensusError::RoundOutOfRange(round));
+        }
+        // Ensure the round is even.
+        if round % 2 != 0 {
+            return Err(ConsensusError::NoLeaderForRound(round));
+        }
+        // Compute the leader from the committee lookback, as the BFT does.
+        let committee_lookback = self
+            .ledger
+            .get_committee_lookback_for_round(round)
+            .map_err(|_| ConsensusError::CommitteeNotFound(round))?;
+        Ok(committee_lookback.get_leader(round)?)
+    }
+
+    /// Returns `true` if this node is the leader of the given round, as elected by the BFT.
+    /// Note: This returns `false` for any round without a known leader; use `get_round_leader` for the reason.
+    pub fn is_leader_for_round(&self, round: u64) -> bool {
+        match self.get_round_leader(round) {
+            Ok(leader) => leader == self.bft.primary().gateway().account().address(),
+            Err(ConsensusError::NoLeaderForRound(_)) => false,
+            Err(e) => {
+                debug!("Failed to compute the leader for round {round} - {e}");
+                false
+            }
+        }
+    }
+
+    /// Returns the estimated time of the next block, from the average time between the recent blocks.
+    /// If fewer than 2 blocks were observed, the estimate is `DEFAULT_BLOCK_TIME` from now.
+    pub fn estimate_next_block_time(&self) -> SystemTime {
+        let block_timestamps = self.block_timestamps.lock();
+        match (block_timestamps.front(), block_timestamps.back()) {
+            (Some(first), Some(last)) if block_timestamps.len() >= 2 => {
+                let elapsed = last.duration_since(*first).unwrap_or_default();
+                *last + elapsed / (block_timestamps.len() - 1) as u32
+            }
+            _ => SystemTime::now() + DEFAULT_BLOCK_TIME,
+        }
+    }
+
+    /// Returns the number of rounds that the ledger is behind the latest round committed by the BFT.
+    /// A growing gap indicates that block production is not keeping up with consensus.
+    pub fn rounds_behind(&self) -> u64 {
+        self.latest_committed_round().saturating_sub(self.ledger.latest_round())
+    }
+
+    /// Returns the minimum fee of a transaction, in microcredits.
+    pub fn min_fee(&self) -> u64 {
+        self.min_fee.load(Ordering::Relaxed)
+    }
+
+    /// Sets the minimum fee of a transaction, in microcredits.
+    /// Note: Transactions that are already in the memory pool are not re-checked.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions, min_fee = min_fee))]
+    pub fn set_min_fee(&self, min_fee: u64) {
+        self.record_span_fields();
+        info!(target: MEMPOOL_TARGET, "Setting the minimum transaction fee to {min_fee} microcredits");
+        self.min_fee.store(min_fee, Ordering::Relaxed);
+    }
+
+    /// Returns the number of rounds retained in the Narwhal storage before garbage collection.
+    pub fn max_gc_rounds(&self) -> u64 {
+        self.config.gc_rounds::<N>()
+    }
+
+    /// Returns the memory pool configuration.
+    pub const fn memory_pool_config(&self) -> &MemoryPoolConfig {
+        &self.config.memory_pool
+    }
+
+    /// Returns a receiver for the lifecycle events of unconfirmed transactions.
+    pub fn subscribe_transaction_events(&self) -> broadcast::Receiver<TransactionEvent<N>> {
+        self.transaction_events.subscribe()
+    }
+
+    /// Returns a receiver for the transactions accepted into the memory pool by the primary.
+    pub fn subscribe_to_new_transactions(&self) -> broadcast::Receiver<Transaction<N>> {
+        self.new_transactions.subscribe()
+    }
+
+    /// Returns a receiver for the blocks confirmed by consensus, in order of height.
+    pub fn subscribe_to_new_blocks(&self) -> broadcast::Receiver<Block<N>> {
+        self.new_blocks.subscribe()
+    }
+
+    /// Returns a receiver for the summaries of the blocks confirmed by consensus, in order of height.
+    pub fn subscribe_blocks(&self) -> broadcast::Receiver<BlockAdvanced> {
+        self.block_events.subscribe()
+    }
+
+    /// Waits until the given transaction is included in a confirmed block, and returns the block
+    /// and the index of the transaction within the block.
+    /// Returns `ConsensusError::TransactionNotInPool` immediately if the transaction is not in the memory pool,
+    /// or `ConsensusError::Timeout` if it is not confirmed within the given timeout.
+    ///
+    /// Note: The block receiver is owned by the returned future, so it is released if the future is dropped.
+    pub async fn wait_for_transaction_confirmation(
+        &self,
+        transaction_id: N::TransactionID,
+        timeout: Duration,
+    ) -> Result<(Block<N>, u32), ConsensusError> {
+        // Subscribe before checking the memory pool, so that a block confirmed in between is not missed.
+        let mut receiver = self.new_blocks.subscribe();
+        if self.get_unconfirmed_transaction(&transaction_id).is_none() {
+            return Err(ConsensusError::TransactionNotInPool(transaction_id.to_string()));
+        }
+        // Returns the index of the transaction in the given block, if it is included.
+        let find_index = |block: &Block<N>| {
+            block.transactions().transaction_ids().position(|id| *id == transaction_id).map(|index| index as u32)
+        };
+        let wait = async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(block) => {
+                        if let Some(index) = find_index(&block) {
+                            return Ok((block, index));
+                        }
+                    }
+                    // If blocks were skipped, check the ledger for the transaction.
+                    Err(broadcast::error::RecvError::Lagged(_)) => {
+                        if let Ok(Some(block_hash)) = self.ledger.find_block_hash(&transaction_id) {
+                            let block = self.get_block_by_hash(&block_hash)?;
+                            if let Some(index) = find_index(&block) {
+                                return Ok((block, index));
+                            }
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return Err(ConsensusError::ShuttingDown),
+                }
+            }
+        };
+        match tokio::time::timeout(timeout, wait).await {
+            Ok(result) => result,
+            Err(_) => Err(ConsensusError::Timeout(format!("transaction '{transaction_id}'"))),
+        }
+    }
+
+    /// Returns a receiver for the subdags committed by the BFT, with their transmissions,
+    /// before they are advanced into blocks.
+    pub fn subscribe_subdags(&self) -> broadcast::Receiver<(Subdag<N>, IndexMap<TransmissionID<N>, Transmission<N>>)> {
+        self.committed_subdags.subscribe()
+    }
+
+    /// Returns the sender for the confirmed blocks.
+    pub fn new_blocks_sender(&self) -> Arc<broadcast::Sender<Block<N>>> {
+        self.new_blocks.clone()
+    }
+
+    /// Waits until a block at or above the given height is confirmed, and returns the first such block.
+    /// If the block is already confirmed, it is returned immediately.
+    /// Returns `ConsensusError::Timeout` if no such block is confirmed within the given timeout.
+    ///
+    /// Note: The block receiver is owned by the returned future, so it is released if the future is dropped.
+    pub async fn wait_for_block(&self, height: u32, timeout: Duration) -> Result<Block<N>, ConsensusError> {
+        // Subscribe before checking the ledger, so that a block confirmed in between is not missed.
+        let mut receiver = self.new_blocks.subscribe();
+        let wait = async move {
+            loop {
+                // Check if the block is already confirmed.
+                if self.ledger.latest_block_height() >= height {
+                    return self.get_block(height);
+                }
+                match receiver.recv().await {
+                    Ok(block) if block.height() >= height => return Ok(block),
+                    Ok(_) => continue,
+                    // If blocks were skipped, check the ledger again.
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return Err(ConsensusError::ShuttingDown),
+                }
+            }
+        };
+        match tokio::time::timeout(timeout, wait).await {
+            Ok(result) => result,
+            Err(_) => Err(ConsensusError::Timeout(format!("block {height}"))),
+        }
+    }
+
+    /// Returns the committee held by the Narwhal storage for its current round, along with that round.
+    ///
+    /// Note: This is the committee lookback for the round, which the BFT uses to certify batches,
+    /// and may differ from the latest committee in the ledger.
+    pub fn committee(&self) -> Result<(Committee<N>, u64), ConsensusError> {
+        let round = self.bft.storage().current_round();
+        let committee_lookback = self
+            .ledger
+            .get_committee_lookback_for_round(round)
+            .map_err(|_| ConsensusError::CommitteeNotFound(round))?;
+        Ok((committee_lookback, round))
+    }
+
+    /// Returns `true` if the given address is a member of the current committee.
+    /// Note: The committee is read from the ledger on each call, so it reflects the latest block.
+    pub fn is_validator(&self, address: &Address<N>) -> bool {
+        self.validator_stake(address).is_some()
+    }
+
+    /// Returns the stake of the given address in the current committee, or `None` if it is not a member.
+    pub fn validator_stake(&self, address: &Address<N>) -> Option<u64> {
+        match self.ledger.current_committee() {
+            Ok(committee) => committee.members().get(address).map(|(stake, ..)| *stake),
+            Err(e) => {
+                warn!("Failed to retrieve the current committee - {e}");
+                None
+            }
+        }
+    }
+
+    /// Returns the primary sender, or `ConsensusError::NotRunning` if consensus has not been started.
+    pub fn primary_sender(&self) -> Result<&PrimarySender<N>, ConsensusError> {
+        self.primary_sender.get().ok_or(ConsensusError::NotRunning)
     }
 }
 
@@ -173,19 +653,393 @@ impl<N: Network> Consensus<N> {
     }
 
     /// Returns the number of unconfirmed ratifications.
+    ///
+    /// Note: This iterates over the ready queue of each worker,
+    /// and is linear in the number of unconfirmed transmissions.
     pub fn num_unconfirmed_ratifications(&self) -> usize {
         self.bft.num_unconfirmed_ratifications()
     }
 
     /// Returns the number of solutions.
+    ///
+    /// Note: This sums a counter from each worker, and does not iterate over the ready queues.
     pub fn num_unconfirmed_solutions(&self) -> usize {
         self.bft.num_unconfirmed_solutions()
     }
 
     /// Returns the number of unconfirmed transactions.
+    ///
+    /// Note: This sums a counter from each worker, and does not iterate over the ready queues.
     pub fn num_unconfirmed_transactions(&self) -> usize {
         self.bft.num_unconfirmed_transactions()
     }
+
+    /// Returns the number of solutions in the inbound queue and the memory pool.
+    fn num_queued_and_unconfirmed_solutions(&self) -> usize {
+        self.solutions_queue.lock().len().saturating_add(self.num_unconfirmed_solutions())
+    }
+
+    /// Returns the number of transactions in the inbound queue and the memory pool.
+    fn num_queued_and_unconfirmed_transactions(&self) -> usize {
+        let num_queued = {
+            let tx_queue = self.transactions_queue.lock();
+            tx_queue.deployments.len() + tx_queue.executions.len()
+        };
+        num_queued.saturating_add(self.num_unconfirmed_transactions())
+    }
+
+    /// Returns `true` if the cumulative proof target, including the unconfirmed solutions, meets the coinbase target.
+    ///
+    /// Note: This deserializes every unconfirmed solution. Frequent pollers should read the cached
+    /// value from `coinbase_ready` instead, which is refreshed whenever solutions are added or a block is advanced.
+    pub fn is_coinbase_target_met(&self) -> Result<bool, ConsensusError> {
+        self.is_coinbase_target_met_with_margin(0)
+    }
+
+    /// Returns `true` if the cumulative proof target, including the unconfirmed solutions,
+    /// meets the coinbase target plus the given margin.
+    pub fn is_coinbase_target_met_with_margin(&self, margin: u128) -> Result<bool, ConsensusError> {
+        let (cumulative_proof_target, coinbase_target) = self.cumulative_proof_and_coinbase_targets()?;
+        Ok(cumulative_proof_target >= coinbase_target.saturating_add(margin))
+    }
+
+    /// Returns the cumulative proof target, including the solutions in the inbound queue and the memory pool,
+    /// and the latest coinbase target.
+    ///
+    /// Note: This reads the running total of the proof target cache, so it does not iterate over the solutions.
+    fn cumulative_proof_and_coinbase_targets(&self) -> Result<(u128, u128), ConsensusError> {
+        let mut cache = self.proof_target_cache.lock();
+        // Retrieve the targets of the latest block, which are only read from the ledger until the first block advance.
+        let (cumulative_proof_target, coinbase_target) = match cache.latest_block_targets() {
+            Some(targets) => targets,
+            None => {
+                let block = self.ledger.latest_block();
+                let targets = (block.header().cumulative_proof_target(), u128::from(block.header().coinbase_target()));
+                cache.set_latest_block_targets(targets.0, targets.1);
+                targets
+            }
+        };
+        Ok((cumulative_proof_target.saturating_add(cache.total()), coinbase_target))
+    }
+
+    /// Resets the proof target cache to the given block, which the ledger has advanced to,
+    /// and to the solutions that are still pending in the inbound queue and the memory pool.
+    ///
+    /// Note: Solutions that left the ready queues for a batch stay in the cache until the next block,
+    /// as they remain pending until they are confirmed.
+    fn reset_proof_target_cache(&self, block: &Block<N>) {
+        // Note: The cache is locked for the duration of the reset, so that solutions added in between are not lost.
+        let mut cache = self.proof_target_cache.lock();
+        let previous = std::mem::take(&mut *cache);
+        cache.set_latest_block_targets(
+            block.header().cumulative_proof_target(),
+            u128::from(block.header().coinbase_target()),
+        );
+        // Returns the proof target of the given solution, reusing the previously computed one if it exists.
+        let proof_target_of = |solution_id: &SolutionID<N>, solution: &Solution<N>| match previous.get(solution_id) {
+            Some(proof_target) => Some(proof_target),
+            None => match self.ledger.get_proof_target(solution) {
+                Ok(proof_target) => Some(proof_target),
+                Err(e) => {
+                    warn!(
+                        target: MEMPOOL_TARGET,
+                        "Failed to compute the proof target of '{}' - {e}",
+                        fmt_id(solution_id)
+                    );
+                    None
+                }
+            },
+        };
+        for (solution_id, solution) in self.solutions_queue.lock().iter() {
+            if let Some(proof_target) = proof_target_of(solution_id, solution) {
+                cache.insert(*solution_id, proof_target);
+            }
+        }
+        for (solution_id, solution) in self.unconfirmed_solutions() {
+            if cache.get(&solution_id).is_some() {
+                continue;
+            }
+            let proof_target = match previous.get(&solution_id) {
+                Some(proof_target) => Some(proof_target),
+                None => match solution.deserialize_blocking() {
+                    Ok(solution) => proof_target_of(&solution_id, &solution),
+                    Err(e) => {
+                        warn!(target: MEMPOOL_TARGET, "Failed to deserialize solution '{}' - {e}", fmt_id(solution_id));
+                        None
+                    }
+                },
+            };
+            if let Some(proof_target) = proof_target {
+                cache.insert(solution_id, proof_target);
+            }
+        }
+    }
+
+    /// Returns the solutions in the inbound queue and the memory pool, ordered by ID.
+    fn pending_solutions(&self) -> Result<BTreeMap<u64, Solution<N>>, ConsensusError> {
+        let mut solutions = BTreeMap::new();
+        for solution in self.solutions_queue.lock().iter().map(|(_, solution)| *solution) {
+            solutions.insert(*solution.id(), solution);
+        }
+        for (solution_id, solution) in self.unconfirmed_solutions() {
+            let solution = solution.deserialize_blocking().map_err(ConsensusError::BftError)?;
+            solutions.insert(*solution_id, solution);
+        }
+        Ok(solutions)
+    }
+
+    /// Returns the solutions in the inbound queue and the memory pool, ordered by ID, up to the first solution at which
+    /// the cumulative proof target reaches the coinbase target plus the given overshoot margin, in percent.
+    ///
+    /// Note: The solutions are ordered by ID, so that the selection is deterministic for the same set of solutions.
+    pub fn candidate_solutions(&self, overshoot_percent: u64) -> Result<Vec<Solution<N>>, ConsensusError> {
+        let candidates = self.candidate_solutions_with_targets(overshoot_percent)?;
+        Ok(candidates.into_iter().map(|(solution, _)| solution).collect())
+    }
+
+    /// Returns the candidate solutions of `candidate_solutions`, with their proof targets.
+    fn candidate_solutions_with_targets(
+        &self,
+        overshoot_percent: u64,
+    ) -> Result<Vec<(Solution<N>, u64)>, ConsensusError> {
+        // Retrieve the latest block.
+        let block = self.ledger.latest_block();
+        // Determine the cumulative proof target at which to stop.
+        let coinbase_target = u128::from(block.header().coinbase_target());
+        let stop_target = coinbase_target.saturating_mul(100 + u128::from(overshoot_percent)) / 100;
+        // Select the solutions, until the cumulative proof target reaches the stop target.
+        let mut cumulative_proof_target = block.header().cumulative_proof_target();
+        let mut candidates = Vec::new();
+        for (solution_id, solution) in self.pending_solutions()? {
+            if cumulative_proof_target >= stop_target {
+                break;
+            }
+            // Note: The proof target is read from the cache, and only computed if the solution is not tracked.
+            let cached_proof_target = self.proof_target_cache.lock().get(&SolutionID::from(solution_id));
+            let proof_target = match cached_proof_target {
+                Some(proof_target) => proof_target,
+                None => self.ledger.get_proof_target(&solution)?,
+            };
+            cumulative_proof_target = cumulative_proof_target.saturating_add(u128::from(proof_target));
+            candidates.push((solution, proof_target));
+        }
+        Ok(candidates)
+    }
+
+    /// Returns the aggregate fee of the transactions that the primary would propose next, in microcredits.
+    ///
+    /// Note: This mirrors the selection of the batch proposal, which takes an equal share of the maximum number of
+    /// transmissions per batch from the ready queue of each worker, skipping the transmissions already in the ledger.
+    fn candidate_fees(&self) -> Result<u64, ConsensusError> {
+        let workers = self.bft.primary().workers();
+        let num_transmissions_per_worker = BatchHeader::<N>::MAX_TRANSMISSIONS_PER_BATCH / workers.len().max(1);
+        let mut total = 0u64;
+        for worker in workers.iter() {
+            let transmission_ids = worker
+                .transmission_ids()
+                .into_iter()
+                .filter(|transmission_id| !self.ledger.contains_transmission(transmission_id).unwrap_or(true));
+            for transmission_id in transmission_ids.take(num_transmissions_per_worker) {
+                if let Some(Transmission::Transaction(transaction)) = worker.get_ready_transmission(transmission_id) {
+                    let transaction = transaction.deserialize_blocking().map_err(ConsensusError::BftError)?;
+                    total = total.saturating_add(fee_of(&transaction));
+                }
+            }
+        }
+        Ok(total)
+    }
+
+    /// Returns an estimate of the reward of the next block, in microcredits, which is the block reward,
+    /// including the fees of the transactions the primary would propose next, and the puzzle reward
+    /// of the candidate solutions.
+    ///
+    /// Note: This is only an estimate, which changes as transactions and solutions arrive or leave the memory pool.
+    /// The transactions in the inbound queue are not counted until they reach the workers. The actual block may
+    /// include a different set of transmissions, depending on the committed subdag.
+    pub fn estimate_next_block_reward(&self) -> Result<u64, ConsensusError> {
+        // Retrieve the latest block.
+        let block = self.ledger.latest_block();
+        // Determine the combined proof target of the candidate solutions.
+        let combined_proof_target = self
+            .candidate_solutions_with_targets(0)?
+            .into_iter()
+            .fold(0u128, |total, (_, proof_target)| total.saturating_add(u128::from(proof_target)));
+        // Compute the coinbase reward, as of the latest block.
+        let cumulative_proof_target = u64::try_from(block.header().cumulative_proof_target())
+            .map_err(|e| anyhow!("Invalid cumulative proof target in block {} - {e}", block.height()))?;
+        let coinbase_reward = coinbase_reward(
+            block.height().saturating_add(1),
+            N::STARTING_SUPPLY,
+            N::ANCHOR_HEIGHT,
+            N::BLOCK_TIME,
+            combined_proof_target,
+            cumulative_proof_target,
+            block.header().coinbase_target(),
+        )?;
+        // Compute the block reward, including the fees of the candidate transactions.
+        let block_reward = block_reward(N::STARTING_SUPPLY, N::BLOCK_TIME, coinbase_reward, self.candidate_fees()?);
+        // Add the puzzle reward, which is half of the coinbase reward.
+        Ok(block_reward.saturating_add(coinbase_reward / 2))
+    }
+
+    /// Verifies the given block is a valid next block, without advancing the ledger.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions, height = block.height()))]
+    pub fn verify_block(&self, block: &Block<N>) -> Result<(), BlockValidationError> {
+        self.record_span_fields();
+        // Ensure the block extends the latest block.
+        self.ensure_block_extends_latest(block)?;
+        // Check the remaining contents of the block, including the solutions and transactions.
+        self.ledger
+            .check_next_block(block)
+            .map_err(|e| BlockValidationError::InvalidBlock { height: block.height(), reason: e.to_string() })
+    }
+
+    /// Ensures the given block follows the latest block height, and builds on the latest block hash.
+    fn ensure_block_extends_latest(&self, block: &Block<N>) -> Result<(), BlockValidationError> {
+        let height = block.height();
+        // Ensure the block height follows the latest block height.
+        let latest_height = self.ledger.latest_block_height();
+        if height != latest_height.saturating_add(1) {
+            return Err(BlockValidationError::InvalidHeight { latest: latest_height, found: height });
+        }
+        // Ensure the block builds on the latest block hash.
+        let latest_hash = self.latest_block_hash();
+        if block.previous_hash() != latest_hash {
+            return Err(BlockValidationError::InvalidPreviousHash {
+                height,
+                expected: latest_hash.to_string(),
+                found: block.previous_hash().to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Verifies the given block is a valid next block, as if the block had been produced at the given timestamp.
+    ///
+    /// The given timestamp must follow the latest block timestamp, which may itself have been overridden.
+    /// The remaining contents of the block are checked in full, as in `verify_block`.
+    #[cfg(any(test, feature = "devnet"))]
+    fn verify_block_at(&self, block: &Block<N>, timestamp: i64) -> Result<(), BlockValidationError> {
+        // Ensure the block extends the latest block.
+        self.ensure_block_extends_latest(block)?;
+        // Ensure the given timestamp follows the latest block timestamp.
+        let latest_timestamp = match self.block_timestamps.lock().back() {
+            Some(latest) => latest.duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs() as i64),
+            None => self.ledger.latest_block().timestamp(),
+        };
+        if timestamp <= latest_timestamp {
+            return Err(BlockValidationError::InvalidTimestamp {
+                height: block.height(),
+                latest: latest_timestamp,
+                found: timestamp,
+            });
+        }
+        // Check the remaining contents of the block, including the solutions and transactions.
+        self.ledger
+            .check_next_block(block)
+            .map_err(|e| BlockValidationError::InvalidBlock { height: block.height(), reason: e.to_string() })
+    }
+
+    /// Advances the ledger to the given block, as if the block had been produced at the given timestamp.
+    ///
+    /// The block is validated in full, with the given timestamp in place of the block timestamp. The given timestamp
+    /// also replaces the block timestamp in the state that consensus derives from blocks, such as the estimate of the
+    /// block time, so that tests may simulate time passing faster than the wall clock.
+    ///
+    /// Warning: This is intended for tests and devnets only. Using it on mainnet data produces a ledger state
+    /// that diverges from the canonical chain.
+    #[cfg(any(test, feature = "devnet"))]
+    pub fn advance_to_next_block_with_timestamp(&self, block: &Block<N>, timestamp: i64) -> Result<(), ConsensusError> {
+        // Ensure the block is a valid next block, at the given timestamp.
+        self.verify_block_at(block, timestamp)?;
+        // Advance to the next block.
+        self.ledger.advance_to_next_block(block)?;
+        debug!(target: BLOCK_TARGET, "Advanced to block {} with an overridden timestamp of {timestamp}", block.height());
+        // Check if the coinbase target is met, now that the block has been advanced.
+        self.reset_proof_target_cache(block);
+        self.update_coinbase_ready();
+        // Notify the subscribers of the new block.
+        self.notify_new_block_at(block, timestamp);
+        Ok(())
+    }
+
+    /// Returns a receiver that is notified whenever the coinbase target becomes met or unmet.
+    ///
+    /// Note: The current value is always available via `borrow`, so a consumer that missed
+    /// a change may also fall back to `is_coinbase_target_met`.
+    pub fn coinbase_ready(&self) -> watch::Receiver<bool> {
+        self.coinbase_ready.subscribe()
+    }
+
+    /// Re-evaluates the coinbase target, and notifies the subscribers if the outcome changed.
+    ///
+    /// The target is only reported as met once it is exceeded by `ConsensusConfig::coinbase_target_margin`,
+    /// and then only reported as unmet once it falls short by more than `ConsensusConfig::coinbase_target_tolerance`.
+    fn update_coinbase_ready(&self) {
+        match self.cumulative_proof_and_coinbase_targets() {
+            Ok((cumulative_proof_target, coinbase_target)) => {
+                self.coinbase_ready.send_if_modified(|current| {
+                    let threshold = coinbase_ready_threshold(coinbase_target, &self.config, *current);
+                    let is_met = cumulative_proof_target >= threshold;
+                    std::mem::replace(current, is_met) != is_met
+                });
+            }
+            Err(e) => warn!("Failed to check if the coinbase target is met - {e}"),
+        }
+    }
+
+    /// Returns a lightweight health report, for liveness and readiness probes.
+    pub fn health_check(&self) -> HealthReport {
+        let primary_sender_initialized = self.primary_sender.get().is_some();
+        let bft_running = primary_sender_initialized && !self.is_shutting_down.load(Ordering::Relaxed);
+        // Determine the age of the latest block.
+        let latest_block = self.ledger.latest_block();
+        let seconds_since_last_block =
+            u64::try_from(snarkos_node_bft::helpers::now().saturating_sub(latest_block.timestamp())).unwrap_or(0);
+        // Determine the overall health.
+        let overall = if !bft_running {
+            HealthStatus::Unhealthy
+        } else if seconds_since_last_block > self.config.max_block_age().as_secs() {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+        HealthReport {
+            bft_running,
+            primary_sender_initialized,
+            latest_height: latest_block.height(),
+            latest_round: latest_block.round(),
+            seconds_since_last_block,
+            mempool_size: self.num_queued_and_unconfirmed_solutions() + self.num_queued_and_unconfirmed_transactions(),
+            handles_alive: self.tasks.all_running(),
+            overall,
+        }
+    }
+
+    /// Returns a diagnostic snapshot of the consensus state.
+    pub fn status(&self) -> Result<ConsensusStatus<N>, ConsensusError> {
+        Ok(ConsensusStatus {
+            latest_height: self.ledger.latest_block_height(),
+            latest_round: self.ledger.latest_round(),
+            bft_leader: self.bft.leader(),
+            committee_size: self.committee()?.0.num_members(),
+            num_unconfirmed_transmissions: self.num_unconfirmed_transmissions(),
+            memory_pool_tx_count: self.num_unconfirmed_transactions(),
+            memory_pool_solution_count: self.num_unconfirmed_solutions(),
+            is_coinbase_target_met: self.is_coinbase_target_met()?,
+        })
+    }
+
+    /// Returns the requirements for a solution to be accepted, as of the latest block.
+    pub fn solution_requirements(&self) -> Result<SolutionRequirements, ConsensusError> {
+        let block = self.ledger.latest_block();
+        Ok(SolutionRequirements {
+            latest_proof_target: block.proof_target(),
+            latest_coinbase_target: block.coinbase_target(),
+            epoch_number: block.height() / N::NUM_BLOCKS_PER_EPOCH,
+            coinbase_target_met: self.is_coinbase_target_met()?,
+        })
+    }
 }
 
 impl<N: Network> Consensus<N> {
@@ -199,42 +1053,205 @@ impl<N: Network> Consensus<N> {
         self.bft.unconfirmed_transmissions()
     }
 
+    /// Returns up to `limit` unconfirmed transmissions after the given cursor, or from the beginning if there is none,
+    /// along with the cursor of the next page, or `None` if this is the last page.
+    ///
+    /// Note: The transmissions are ordered by worker, then by insertion into the worker's ready queue, so a cursor
+    /// remains valid as long as it is not removed from the memory pool. If the cursor itself was removed,
+    /// `ConsensusError::CursorNotFound` is returned, and the caller may restart from the beginning.
+    pub fn get_unconfirmed_transmissions_paginated(
+        &self,
+        cursor: Option<TransmissionID<N>>,
+        limit: usize,
+    ) -> Result<(Vec<(TransmissionID<N>, Transmission<N>)>, Option<TransmissionID<N>>), ConsensusError> {
+        // Ensure the page limit is non-zero, so that every page makes progress.
+        if limit == 0 {
+            return Err(ConsensusError::ZeroPageLimit);
+        }
+        // Retrieve one more transmission than the limit, to determine whether more transmissions follow.
+        let mut page = self
+            .bft
+            .unconfirmed_transmissions_after(cursor, limit.saturating_add(1))
+            .ok_or_else(|| ConsensusError::CursorNotFound(cursor.map(fmt_id).unwrap_or_default()))?;
+        // Return the next cursor, if more transmissions follow.
+        let next_cursor = match page.len() > limit {
+            true => {
+                page.truncate(limit);
+                page.last().map(|(transmission_id, _)| *transmission_id)
+            }
+            false => None,
+        };
+        Ok((page, next_cursor))
+    }
+
     /// Returns the unconfirmed solutions.
+    /// Note: The solutions are ordered by worker, then by insertion into the worker's ready queue.
     pub fn unconfirmed_solutions(&self) -> impl '_ + Iterator<Item = (SolutionID<N>, Data<Solution<N>>)> {
         self.bft.unconfirmed_solutions()
     }
 
     /// Returns the unconfirmed transactions.
+    /// Note: The transactions are ordered by worker, then by insertion into the worker's ready queue.
     pub fn unconfirmed_transactions(&self) -> impl '_ + Iterator<Item = (N::TransactionID, Data<Transaction<N>>)> {
         self.bft.unconfirmed_transactions()
     }
+
+    /// Returns a snapshot of the IDs in the inbound queues and the memory pool, without mutating either.
+    ///
+    /// Note: Each inbound queue and each worker is locked separately, so every part of the snapshot is
+    /// consistent on its own, but the snapshot as a whole is not taken at a single point in time.
+    /// A transmission that moves from an inbound queue to the memory pool concurrently may appear in both, or neither.
+    pub fn memory_pool_snapshot(&self) -> MemoryPoolSnapshot<N> {
+        let queued_solution_ids = self.solutions_queue.lock().iter().map(|(id, _)| *id).collect();
+        let queued_transaction_ids = {
+            let tx_queue = self.transactions_queue.lock();
+            tx_queue.deployments.iter().chain(tx_queue.executions.iter()).map(|(id, _)| *id).collect()
+        };
+        let unconfirmed_transmission_ids = self.unconfirmed_transmission_ids().collect();
+        MemoryPoolSnapshot { queued_solution_ids, queued_transaction_ids, unconfirmed_transmission_ids }
+    }
+
+    /// Returns the unconfirmed transaction for the given ID, if it exists in the inbound queue or the memory pool.
+    /// If the transaction is still serialized, it is deserialized on retrieval.
+    pub fn get_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> Option<Transaction<N>> {
+        // Check the inbound queue.
+        {
+            let tx_queue = self.transactions_queue.lock();
+            if let Some(entry) =
+                tx_queue.deployments.peek(transaction_id).or_else(|| tx_queue.executions.peek(transaction_id))
+            {
+                return Some(entry.transaction.clone());
+            }
+        }
+        // Check the memory pool.
+        match self.bft.get_unconfirmed_transmission(transaction_id)? {
+            Transmission::Transaction(transaction) => match transaction.deserialize_blocking() {
+                Ok(transaction) => Some(transaction),
+                Err(e) => {
+                    warn!(
+                        target: MEMPOOL_TARGET,
+                        "Failed to deserialize unconfirmed transaction '{}' - {e}",
+                        fmt_id(transaction_id)
+                    );
+                    None
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the unconfirmed solution for the given ID, if it exists in the inbound queue or the memory pool.
+    /// If the solution is still serialized, it is deserialized on retrieval.
+    pub fn get_unconfirmed_solution(&self, solution_id: &SolutionID<N>) -> Option<Solution<N>> {
+        // Check the inbound queue.
+        if let Some(solution) = self.solutions_queue.lock().peek(solution_id) {
+            return Some(*solution);
+        }
+        // Check the memory pool.
+        match self.bft.get_unconfirmed_transmission(*solution_id)? {
+            Transmission::Solution(solution) => match solution.deserialize_blocking() {
+                Ok(solution) => Some(solution),
+                Err(e) => {
+                    warn!(
+                        target: MEMPOOL_TARGET,
+                        "Failed to deserialize unconfirmed solution '{}' - {e}",
+                        fmt_id(solution_id)
+                    );
+                    None
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the aggregate fee of the unconfirmed transactions, in the inbound queue and the memory pool,
+    /// in microcredits.
+    ///
+    /// Note: The fees in the inbound queue are cached, and updated on each insertion and removal. The ready queues
+    /// of the workers are drained by the primary without notifying consensus, so their fees are summed on each call.
+    pub fn pending_fees_total(&self) -> Result<u64, ConsensusError> {
+        let queued_fees = self.transactions_queue.lock().pending_fees();
+        Ok(queued_fees.saturating_add(self.unconfirmed_fees()?))
+    }
+
+    /// Recomputes the aggregate fee of the unconfirmed transactions, in microcredits, without the cached total.
+    pub fn recalculate_pending_fees(&self) -> Result<u64, ConsensusError> {
+        let queued_fees = self.transactions_queue.lock().recalculate_pending_fees();
+        Ok(queued_fees.saturating_add(self.unconfirmed_fees()?))
+    }
+
+    /// Returns the aggregate fee of the unconfirmed transactions in the memory pool, in microcredits.
+    fn unconfirmed_fees(&self) -> Result<u64, ConsensusError> {
+        self.unconfirmed_transactions().try_fold(0u64, |total, (_, transaction)| {
+            let transaction = transaction.deserialize_blocking().map_err(ConsensusError::BftError)?;
+            Ok(total.saturating_add(fee_of(&transaction)))
+        })
+    }
 }
 
 impl<N: Network> Consensus<N> {
     /// Adds the given unconfirmed solution to the memory pool.
-    pub async fn add_unconfirmed_solution(&self, solution: Solution<N>) -> Result<()> {
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions, solution_id = %solution.id()))]
+    pub async fn add_unconfirmed_solution(&self, solution: Solution<N>) -> Result<(), ConsensusError> {
+        self.record_span_fields();
+        // Ensure consensus is not shutting down.
+        if self.is_shutting_down.load(Ordering::Relaxed) {
+            return Err(ConsensusError::ShuttingDown);
+        }
         #[cfg(feature = "metrics")]
         {
             metrics::increment_gauge(metrics::consensus::UNCONFIRMED_SOLUTIONS, 1f64);
             metrics::increment_gauge(metrics::consensus::UNCONFIRMED_TRANSMISSIONS, 1f64);
         }
+        // Retrieve the primary sender.
+        let primary_sender = self.primary_sender()?;
         // Process the unconfirmed solution.
         {
             let solution_id = solution.id();
 
-            // Check if the transaction was recently seen.
+            // Check if the solution was recently seen.
+            // Note: The solution ID commits to the epoch, so a repeated ID is the same solution in the same epoch.
             if self.seen_solutions.lock().put(solution_id, ()).is_some() {
-                // If the transaction was recently seen, return early.
-                return Ok(());
+                return Err(ConsensusError::SolutionAlreadyExists(fmt_id(solution_id)));
             }
             // Check if the solution already exists in the ledger.
             if self.ledger.contains_transmission(&TransmissionID::from(solution_id))? {
-                bail!("Solution '{}' exists in the ledger {}", fmt_id(solution_id), "(skipping)".dimmed());
+                return Err(ConsensusError::SolutionAlreadyExists(fmt_id(solution_id)));
             }
+            // Check if the solution is already unconfirmed in the memory pool.
+            if self.bft.contains_transmission(solution_id) {
+                return Err(ConsensusError::SolutionAlreadyExists(fmt_id(solution_id)));
+            }
+            // Compute the proof target of the solution, which is tracked while the solution is pending.
+            let proof_target =
+                self.ledger.get_proof_target(&solution).map_err(|e| ConsensusError::SolutionInvalid(e.to_string()))?;
             // Add the solution to the memory pool.
-            trace!("Received unconfirmed solution '{}' in the queue", fmt_id(solution_id));
-            if self.solutions_queue.lock().put(solution_id, solution).is_some() {
-                bail!("Solution '{}' exists in the memory pool", fmt_id(solution_id));
+            trace!(target: MEMPOOL_TARGET, "Received unconfirmed solution '{}' in the queue", fmt_id(solution_id));
+            let mut queue = self.solutions_queue.lock();
+            let evicted_id = match queue.push(solution_id, solution) {
+                Some((id, _)) if id == solution_id => {
+                    return Err(ConsensusError::SolutionAlreadyExists(fmt_id(solution_id)));
+                }
+                Some((id, _)) => Some(id),
+                None => None,
+            };
+            #[cfg(feature = "metrics")]
+            {
+                metrics::increment_counter(metrics::memory_pool::SOLUTIONS_ADDED);
+                if evicted_id.is_some() {
+                    metrics::increment_counter(metrics::memory_pool::SOLUTIONS_REMOVED);
+                }
+                metrics::gauge(metrics::memory_pool::SOLUTIONS_CURRENT, queue.len() as f64);
+            }
+            // Release the queue before tracking the proof target, as the cache is locked before the queue elsewhere.
+            drop(queue);
+            // Track the proof target of the solution.
+            let mut proof_target_cache = self.proof_target_cache.lock();
+            proof_target_cache.insert(solution_id, proof_target);
+            // If the queue is full, the least-recently-used solution is evicted.
+            if let Some(evicted_id) = evicted_id {
+                debug!(target: MEMPOOL_TARGET, "Evicted solution '{}' from the full queue", fmt_id(evicted_id));
+                proof_target_cache.remove(&evicted_id);
             }
         }
 
@@ -255,62 +1272,233 @@ impl<N: Network> Consensus<N> {
             // Determine the number of solutions to send.
             let num_solutions = queue.len().min(capacity);
             // Drain the solutions from the queue.
-            (0..num_solutions).filter_map(|_| queue.pop_lru().map(|(_, solution)| solution)).collect::<Vec<_>>()
+            let solutions =
+                (0..num_solutions).filter_map(|_| queue.pop_lru().map(|(_, solution)| solution)).collect::<Vec<_>>();
+            #[cfg(feature = "metrics")]
+            {
+                metrics::counter(metrics::memory_pool::SOLUTIONS_REMOVED, solutions.len() as u64);
+                metrics::gauge(metrics::memory_pool::SOLUTIONS_CURRENT, queue.len() as f64);
+            }
+            solutions
         };
         // Iterate over the solutions.
         for solution in solutions.into_iter() {
             let solution_id = solution.id();
-            trace!("Adding unconfirmed solution '{}' to the memory pool...", fmt_id(solution_id));
+            trace!(
+                target: MEMPOOL_TARGET,
+                "Adding unconfirmed solution '{}' to the memory pool...",
+                fmt_id(solution_id)
+            );
             // Send the unconfirmed solution to the primary.
-            if let Err(e) = self.primary_sender().send_unconfirmed_solution(solution_id, Data::Object(solution)).await {
+            if let Err(e) = primary_sender.send_unconfirmed_solution(solution_id, Data::Object(solution)).await {
+                // Stop tracking the solution, as it is no longer pending.
+                self.proof_target_cache.lock().remove(&solution_id);
                 // If the BFT is synced, then log the warning.
                 if self.bft.is_synced() {
-                    warn!("Failed to add unconfirmed solution '{}' to the memory pool - {e}", fmt_id(solution_id));
+                    warn!(
+                        target: MEMPOOL_TARGET,
+                        "Failed to add unconfirmed solution '{}' to the memory pool - {e}",
+                        fmt_id(solution_id)
+                    );
                 }
             }
         }
+        // Check if the new solutions have met the coinbase target.
+        self.update_coinbase_ready();
         Ok(())
     }
 
-    /// Adds the given unconfirmed transaction to the memory pool.
-    pub async fn add_unconfirmed_transaction(&self, transaction: Transaction<N>) -> Result<()> {
-        #[cfg(feature = "metrics")]
-        {
-            metrics::increment_gauge(metrics::consensus::UNCONFIRMED_TRANSACTIONS, 1f64);
-            metrics::increment_gauge(metrics::consensus::UNCONFIRMED_TRANSMISSIONS, 1f64);
-        }
-        // Process the unconfirmed transaction.
-        {
-            let transaction_id = transaction.id();
+    /// Checks that the given transaction is valid and not yet in the ledger, without adding it to the memory pool.
+    /// This performs the same verification as the memory pool, so clients can pre-flight a transaction.
+    ///
+    /// Note: A transaction that passes this check may still be rejected later, if a conflicting transaction,
+    /// such as one spending the same records, is confirmed first.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(latest_height, num_transmissions, transaction_id = %transaction.id())
+    )]
+    pub async fn check_transaction(&self, transaction: &Transaction<N>) -> Result<(), ConsensusError> {
+        self.record_span_fields();
+        // Perform the inexpensive checks first.
+        self.precheck_transaction(transaction)?;
+        // Check that the transaction is well-formed, including its proofs, its fee, and its inputs being unspent.
+        let transaction_id = transaction.id();
+        self.ledger
+            .check_transaction_basic(transaction_id, Data::Object(transaction.clone()))
+            .await
+            .map_err(|e| ConsensusError::TransactionInvalid(e.to_string()))
+    }
 
-            // Check that the transaction is not a fee transaction.
-            if transaction.is_fee() {
-                bail!("Transaction '{}' is a fee transaction {}", fmt_id(transaction_id), "(skipping)".dimmed());
-            }
-            // Check if the transaction was recently seen.
-            if self.seen_transactions.lock().put(transaction_id, ()).is_some() {
-                // If the transaction was recently seen, return early.
-                return Ok(());
-            }
-            // Check if the transaction already exists in the ledger.
-            if self.ledger.contains_transmission(&TransmissionID::from(&transaction_id))? {
-                bail!("Transaction '{}' exists in the ledger {}", fmt_id(transaction_id), "(skipping)".dimmed());
-            }
-            // Add the transaction to the memory pool.
-            trace!("Received unconfirmed transaction '{}' in the queue", fmt_id(transaction_id));
-            if transaction.is_deploy() {
-                if self.transactions_queue.lock().deployments.put(transaction_id, transaction).is_some() {
-                    bail!("Transaction '{}' exists in the memory pool", fmt_id(transaction_id));
-                }
-            } else if self.transactions_queue.lock().executions.put(transaction_id, transaction).is_some() {
-                bail!("Transaction '{}' exists in the memory pool", fmt_id(transaction_id));
+    /// Performs the inexpensive checks of `check_transaction`, without verifying the transaction proofs:
+    /// the transaction must not be a fee transaction, must be within the size limit, must meet the minimum fee,
+    /// and must not already be in the ledger.
+    fn precheck_transaction(&self, transaction: &Transaction<N>) -> Result<(), ConsensusError> {
+        let transaction_id = transaction.id();
+        // Check that the transaction is not a fee transaction.
+        if transaction.is_fee() {
+            let reason = format!("'{}' is a fee transaction", fmt_id(transaction_id));
+            return Err(ConsensusError::TransactionInvalid(reason));
+        }
+        // Check that the transaction is within the size limit.
+        let transaction_size =
+            transaction.to_bytes_le().map_err(|e| ConsensusError::TransactionInvalid(e.to_string()))?.len();
+        if transaction_size > self.config.memory_pool.max_transaction_size_bytes {
+            let reason = format!(
+                "'{}' is {transaction_size} bytes, exceeding the limit of {} bytes",
+                fmt_id(transaction_id),
+                self.config.memory_pool.max_transaction_size_bytes
+            );
+            return Err(ConsensusError::TransactionInvalid(reason));
+        }
+        // Check that the transaction fee meets the minimum.
+        let required = self.min_fee();
+        if required > 0 {
+            let actual = *transaction.fee_amount().map_err(|e| ConsensusError::TransactionInvalid(e.to_string()))?;
+            if actual < required {
+                return Err(ConsensusError::FeeTooLow { required, actual });
+            }
+        }
+        // Check if the transaction already exists in the ledger.
+        if self.ledger.contains_transmission(&TransmissionID::from(&transaction_id))? {
+            return Err(ConsensusError::TransactionAlreadyExists(fmt_id(transaction_id)));
+        }
+        Ok(())
+    }
+
+    /// Adds the given unconfirmed transaction to the memory pool.
+    ///
+    /// If the transaction spends the same inputs as a queued transaction, it replaces the queued transaction
+    /// when its fee-per-byte is at least `MemoryPoolConfig::min_fee_bump_percent` higher, and otherwise
+    /// `ConsensusError::ReplacementUnderpriced` is returned.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(latest_height, num_transmissions, transaction_id = %transaction.id())
+    )]
+    pub async fn add_unconfirmed_transaction(&self, transaction: Transaction<N>) -> Result<(), ConsensusError> {
+        self.record_span_fields();
+        self.try_add_unconfirmed_transaction(transaction, None).await.map(|_| ())
+    }
+
+    /// Adds the given unconfirmed transaction to the memory pool, waiting at most `timeout` for the primary
+    /// to accept the queued transactions. Returns `ConsensusError::Backpressure` if the primary is still busy
+    /// after the timeout, in which case the remaining transactions are kept in the inbound queue.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(latest_height, num_transmissions, transaction_id = %transaction.id())
+    )]
+    pub async fn add_unconfirmed_transaction_with_timeout(
+        &self,
+        transaction: Transaction<N>,
+        timeout: Duration,
+    ) -> Result<(), ConsensusError> {
+        self.record_span_fields();
+        self.try_add_unconfirmed_transaction(transaction, Some(timeout)).await.map(|_| ())
+    }
+
+    /// Adds the given unconfirmed transaction to the memory pool, as `add_unconfirmed_transaction` does,
+    /// and returns whether it replaced a conflicting transaction, instead of failing if the fee bump is insufficient.
+    ///
+    /// Note: Only transactions in the inbound queue can be replaced, as the primary does not retract transmissions.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(latest_height, num_transmissions, transaction_id = %transaction.id())
+    )]
+    pub async fn try_replace_transaction(
+        &self,
+        transaction: Transaction<N>,
+    ) -> Result<ReplacementOutcome<N>, ConsensusError> {
+        self.record_span_fields();
+        match self.try_add_unconfirmed_transaction(transaction, None).await {
+            Ok(Some(replaced)) => Ok(ReplacementOutcome::Replaced(replaced)),
+            Ok(None) => Ok(ReplacementOutcome::New),
+            Err(ConsensusError::ReplacementUnderpriced(_)) => Ok(ReplacementOutcome::NotReplaced),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Adds the given unconfirmed transaction to the memory pool, with an optional timeout for the primary channel.
+    /// Returns the queued transaction that was replaced, if the transaction conflicted with one.
+    async fn try_add_unconfirmed_transaction(
+        &self,
+        transaction: Transaction<N>,
+        timeout: Option<Duration>,
+    ) -> Result<Option<Transaction<N>>, ConsensusError> {
+        // Ensure consensus is not shutting down.
+        if self.is_shutting_down.load(Ordering::Relaxed) {
+            return Err(ConsensusError::ShuttingDown);
+        }
+        #[cfg(feature = "metrics")]
+        {
+            metrics::increment_gauge(metrics::consensus::UNCONFIRMED_TRANSACTIONS, 1f64);
+            metrics::increment_gauge(metrics::consensus::UNCONFIRMED_TRANSMISSIONS, 1f64);
+        }
+        // Retrieve the primary sender.
+        let primary_sender = self.primary_sender()?;
+        // Process the unconfirmed transaction.
+        let mut replaced = None;
+        {
+            let transaction_id = transaction.id();
+            // If the transaction was recently seen, or is already in the memory pool, return early.
+            if !self.validate_unconfirmed_transaction(&transaction).await? {
+                return Ok(None);
+            }
+            // Add the transaction to the memory pool, replacing a conflicting transaction in the queue, if any.
+            trace!(
+                target: MEMPOOL_TARGET,
+                "Received unconfirmed transaction '{}' in the queue",
+                fmt_id(transaction_id)
+            );
+            let mut tx_queue = self.transactions_queue.lock();
+            let evicted_id = if let Some(existing_id) = tx_queue.find_conflict(&transaction) {
+                let bump_percent = self.config.memory_pool.min_fee_bump_percent;
+                match tx_queue.replace(&existing_id, transaction_id, transaction, bump_percent)? {
+                    (ReplacementOutcome::Replaced(transaction), evicted_id) => {
+                        replaced = Some(transaction);
+                        evicted_id
+                    }
+                    _ => {
+                        drop(tx_queue);
+                        // Forget the transaction, so that a resubmission with a higher fee is not skipped.
+                        self.seen_transactions.lock().pop(&transaction_id);
+                        return Err(ConsensusError::ReplacementUnderpriced(fmt_id(transaction_id).to_string()));
+                    }
+                }
+            } else {
+                match tx_queue.insert(transaction_id, transaction) {
+                    Ok(evicted_id) => evicted_id,
+                    Err(e) => {
+                        drop(tx_queue);
+                        // Forget the transaction, so that a resubmission with a higher fee is not skipped.
+                        self.seen_transactions.lock().pop(&transaction_id);
+                        return Err(e);
+                    }
+                }
+            };
+            drop(tx_queue);
+            // If the queue is full, a transaction is evicted according to the eviction policy.
+            if let Some(evicted_id) = evicted_id {
+                debug!(target: MEMPOOL_TARGET, "Evicted transaction '{}' from the full queue", fmt_id(evicted_id));
+                self.transaction_events.send(TransactionEvent::Dropped(evicted_id, DropReason::Evicted)).ok();
+            }
+            if let Some(replaced) = &replaced {
+                debug!(
+                    target: MEMPOOL_TARGET,
+                    "Replaced transaction '{}' by '{}'",
+                    fmt_id(replaced.id()),
+                    fmt_id(transaction_id)
+                );
+                self.transaction_events.send(TransactionEvent::Replaced(replaced.id(), transaction_id)).ok();
             }
         }
 
         // If the memory pool of this node is full, return early.
         let num_unconfirmed_transmissions = self.num_unconfirmed_transmissions();
         if num_unconfirmed_transmissions >= Primary::<N>::MAX_TRANSMISSIONS_TOLERANCE {
-            return Ok(());
+            return Ok(replaced);
         }
         // Retrieve the transactions.
         let transactions = {
@@ -326,35 +1514,769 @@ impl<N: Network> Consensus<N> {
             // Note: interleaving ensures we will never have consecutive invalid deployments blocking the queue.
             let selector_iter = (0..num_deployments).map(|_| true).interleave((0..num_executions).map(|_| false));
             // Drain the transactions from the queue, interleaving deployments and executions.
-            selector_iter
-                .filter_map(|select_deployment| {
-                    if select_deployment {
-                        tx_queue.deployments.pop_lru().map(|(_, tx)| tx)
-                    } else {
-                        tx_queue.executions.pop_lru().map(|(_, tx)| tx)
+            selector_iter.filter_map(|select_deployment| tx_queue.pop_lru(select_deployment)).collect_vec()
+        };
+        // Send the transactions to the primary.
+        self.send_unconfirmed_transactions(primary_sender, transactions, timeout).await?;
+        Ok(replaced)
+    }
+
+    /// Adds the given unconfirmed transactions to the memory pool in a batch, returning the result of each
+    /// transaction in order. The transactions bypass the inbound queue, and are sent directly to the primary.
+    ///
+    /// If the primary saturates, the remaining transactions are not sent, and are marked as
+    /// `ConsensusError::Backpressure`, so that they may be resubmitted later.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(latest_height, num_transmissions, num_transactions = transactions.len())
+    )]
+    pub async fn add_unconfirmed_transactions(
+        &self,
+        transactions: Vec<Transaction<N>>,
+    ) -> Vec<Result<(), ConsensusError>> {
+        self.record_span_fields();
+        // Ensure consensus is not shutting down.
+        if self.is_shutting_down.load(Ordering::Relaxed) {
+            return transactions.iter().map(|_| Err(ConsensusError::ShuttingDown)).collect();
+        }
+        // Retrieve the primary sender.
+        let Ok(primary_sender) = self.primary_sender() else {
+            return transactions.iter().map(|_| Err(ConsensusError::NotRunning)).collect();
+        };
+
+        let mut results = Vec::with_capacity(transactions.len());
+        let mut transactions = transactions.into_iter();
+        while let Some(transaction) = transactions.next() {
+            let transaction_id = transaction.id();
+            // If the memory pool of this node is full, mark this and the remaining transactions as backpressured.
+            // Note: This is checked before validation, so that a client retrying after backpressure is not charged.
+            if self.num_unconfirmed_transmissions() >= Primary::<N>::MAX_TRANSMISSIONS_TOLERANCE {
+                results.push(Err(ConsensusError::Backpressure));
+                results.extend(transactions.map(|_| Err(ConsensusError::Backpressure)));
+                break;
+            }
+            // Validate the transaction.
+            match self.validate_unconfirmed_transaction(&transaction).await {
+                Ok(true) => (),
+                // If the transaction was recently seen, or is already in the memory pool, skip it.
+                Ok(false) => {
+                    results.push(Ok(()));
+                    continue;
+                }
+                Err(e) => {
+                    results.push(Err(e));
+                    continue;
+                }
+            }
+            // Retain a copy of the transaction for the subscribers, if there are any.
+            let new_transaction = (self.new_transactions.receiver_count() > 0).then(|| transaction.clone());
+            // Retain a copy of the fee payer, to take its token once the primary accepts the transaction.
+            let payer = fee_payer(&transaction);
+            // Send the unconfirmed transaction to the primary, without waiting for capacity.
+            let result = primary_sender
+                .send_unconfirmed_transaction_with_timeout(transaction_id, Data::Object(transaction), Duration::ZERO)
+                .await;
+            match result {
+                Ok(None) => {
+                    self.charge_rate_limit(payer);
+                    self.transaction_events.send(TransactionEvent::Accepted(transaction_id)).ok();
+                    // Notify the subscribers, now that the primary has accepted the transaction.
+                    if let Some(transaction) = new_transaction {
+                        self.new_transactions.send(transaction).ok();
                     }
-                })
-                .collect_vec()
+                    results.push(Ok(()));
+                }
+                // If the primary is saturated, mark this and the remaining transactions as backpressured.
+                Ok(Some(_)) => {
+                    // Forget the transaction, so that a resubmission is not skipped.
+                    self.seen_transactions.lock().pop(&transaction_id);
+                    results.push(Err(ConsensusError::Backpressure));
+                    results.extend(transactions.map(|_| Err(ConsensusError::Backpressure)));
+                    break;
+                }
+                Err(e) => {
+                    self.reject_unconfirmed_transaction(transaction_id, e.to_string());
+                    results.push(Err(ConsensusError::TransactionInvalid(e.to_string())));
+                }
+            }
+        }
+        results
+    }
+
+    /// Handles the rejection of the given transaction by the primary, which failed to verify it.
+    fn reject_unconfirmed_transaction(&self, transaction_id: N::TransactionID, reason: String) {
+        // Forget the transaction, so that a resubmission is rejected with the reason instead of skipped.
+        self.seen_transactions.lock().pop(&transaction_id);
+        // Remember the verification failure, so that a resubmission is rejected without being verified again.
+        self.invalid_transactions.lock().put(transaction_id, (Instant::now(), reason.clone()));
+        self.transaction_events.send(TransactionEvent::Dropped(transaction_id, DropReason::Rejected(reason))).ok();
+    }
+
+    /// Validates the given unconfirmed transaction, and marks it as seen.
+    /// Returns `false` if the transaction was recently seen, or is already in the memory pool.
+    async fn validate_unconfirmed_transaction(&self, transaction: &Transaction<N>) -> Result<bool, ConsensusError> {
+        let transaction_id = transaction.id();
+        // Check that the transaction was not recently evicted.
+        {
+            let mut evicted_transactions = self.evicted_transactions.lock();
+            match evicted_transactions.peek(&transaction_id) {
+                Some(evicted_at) if evicted_at.elapsed() < EVICTION_COOLDOWN => {
+                    return Err(ConsensusError::TransactionEvicted(fmt_id(transaction_id).to_string()));
+                }
+                Some(_) => {
+                    evicted_transactions.pop(&transaction_id);
+                }
+                None => (),
+            }
+        }
+        // Check that the transaction did not recently fail verification, without verifying it again.
+        {
+            let mut invalid_transactions = self.invalid_transactions.lock();
+            match invalid_transactions.peek(&transaction_id) {
+                Some((failed_at, reason)) if failed_at.elapsed() < INVALID_TRANSACTION_TTL => {
+                    return Err(ConsensusError::TransactionInvalid(reason.clone()));
+                }
+                Some(_) => {
+                    invalid_transactions.pop(&transaction_id);
+                }
+                None => (),
+            }
+        }
+        // Check if the transaction was recently seen.
+        // Note: This precedes the capacity check, so that resubmitting a queued transaction is skipped,
+        // rather than rejected, when the memory pool is full.
+        if self.seen_transactions.lock().put(transaction_id, ()).is_some() {
+            return Ok(false);
+        }
+        // Check that the number of unconfirmed transactions is below the limit.
+        if self.num_queued_and_unconfirmed_transactions() >= self.config.memory_pool.max_transactions {
+            // Forget the transaction, so that a resubmission is not skipped once there is room.
+            self.seen_transactions.lock().pop(&transaction_id);
+            return Err(ConsensusError::MemoryPoolFull);
+        }
+        // Perform the inexpensive checks of the transaction, before it is queued.
+        // Note: The proofs are only verified by the worker, when the transaction enters the ready queue,
+        // so that the transaction is not verified twice.
+        if let Err(e) = self.precheck_transaction(transaction) {
+            // Forget the transaction, so that a resubmission is rejected with the reason instead of skipped.
+            self.seen_transactions.lock().pop(&transaction_id);
+            // Remember the verification failure, so that a resubmission is rejected without being verified again.
+            if let ConsensusError::TransactionInvalid(reason) = &e {
+                self.invalid_transactions.lock().put(transaction_id, (Instant::now(), reason.clone()));
+            }
+            return Err(e);
+        }
+        // Check that the fee payer is within the rate limit.
+        // Note: The token is only taken once the primary accepts the transaction, so that transactions which are
+        // rejected or backpressured are not charged, and a forged fee payer cannot drain the bucket of another address.
+        if let Err(e) = self.check_rate_limit(transaction) {
+            // Forget the transaction, so that a resubmission is not skipped.
+            self.seen_transactions.lock().pop(&transaction_id);
+            return Err(e);
+        }
+        // Check if the transaction is already unconfirmed in the memory pool.
+        if self.bft.contains_transmission(&transaction_id) {
+            trace!(
+                target: MEMPOOL_TARGET,
+                "Transaction '{}' is already in the memory pool {}",
+                fmt_id(transaction_id),
+                "(skipping)".dimmed()
+            );
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// Checks that the fee payer of the given transaction has a token left, if rate limiting is enabled,
+    /// without taking it. Returns `ConsensusError::RateLimited` if the fee payer exceeded the rate limit.
+    ///
+    /// Note: Transactions with a private fee are not rate limited, as they do not reveal their fee payer.
+    fn check_rate_limit(&self, transaction: &Transaction<N>) -> Result<(), ConsensusError> {
+        let (Some(limit), Some(payer)) = (self.config.per_address_rate, fee_payer(transaction)) else {
+            return Ok(());
+        };
+        let mut rate_limiter = self.rate_limiter.lock();
+        let bucket = rate_limiter.get_or_insert_mut(payer, || TokenBucket::new(&limit));
+        match bucket.has_token_at(&limit, Instant::now()) {
+            true => Ok(()),
+            false => Err(ConsensusError::RateLimited(payer.to_string())),
+        }
+    }
+
+    /// Takes a token from the bucket of the given fee payer, if rate limiting is enabled.
+    /// The transaction of the fee payer must already be accepted by the primary, which verified it,
+    /// so that its fee payer is authentic.
+    fn charge_rate_limit(&self, payer: Option<Address<N>>) {
+        let (Some(limit), Some(payer)) = (self.config.per_address_rate, payer) else {
+            return;
         };
+        let mut rate_limiter = self.rate_limiter.lock();
+        rate_limiter.get_or_insert_mut(payer, || TokenBucket::new(&limit)).try_take_at(&limit, Instant::now());
+    }
+
+    /// Resends the transactions that have waited in the inbound queue for at least the given age to the primary,
+    /// returning the number of transactions accepted by the primary.
+    ///
+    /// Note: Transactions that already exist in the memory pool are skipped, so rebroadcasting is idempotent.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions))]
+    pub async fn rebroadcast_stuck_transactions(&self, age_threshold: Duration) -> Result<usize, ConsensusError> {
+        self.record_span_fields();
+        // Retrieve the primary sender.
+        let primary_sender = self.primary_sender()?;
+        // Determine the available capacity.
+        let capacity = Primary::<N>::MAX_TRANSMISSIONS_TOLERANCE.saturating_sub(self.num_unconfirmed_transmissions());
+        // Take the stuck transactions from the queue.
+        let transactions = self.transactions_queue.lock().pop_older_than(age_threshold, capacity);
+        // Skip the transactions that already exist in the memory pool.
+        let transactions =
+            transactions.into_iter().filter(|transaction| !self.bft.contains_transmission(&transaction.id())).collect();
+        // Send the transactions to the primary.
+        self.send_unconfirmed_transactions(primary_sender, transactions, None).await
+    }
+
+    /// Sends the given transactions to the primary, returning the number of transactions accepted.
+    ///
+    /// If a timeout is given and the primary channel does not accept a transaction before it elapses,
+    /// the unsent transactions are returned to the inbound queue and `ConsensusError::Backpressure` is returned.
+    async fn send_unconfirmed_transactions(
+        &self,
+        primary_sender: &PrimarySender<N>,
+        transactions: Vec<Transaction<N>>,
+        timeout: Option<Duration>,
+    ) -> Result<usize, ConsensusError> {
+        // Determine the deadline for the primary channel, if any.
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut num_accepted = 0;
         // Iterate over the transactions.
-        for transaction in transactions.into_iter() {
+        let mut transactions = transactions.into_iter();
+        while let Some(transaction) = transactions.next() {
             let transaction_id = transaction.id();
-            trace!("Adding unconfirmed transaction '{}' to the memory pool...", fmt_id(transaction_id));
+            trace!(
+                target: MEMPOOL_TARGET,
+                "Adding unconfirmed transaction '{}' to the memory pool...",
+                fmt_id(transaction_id)
+            );
+            // Retain a copy of the transaction for the subscribers, if there are any.
+            let new_transaction = (self.new_transactions.receiver_count() > 0).then(|| transaction.clone());
+            // Retain a copy of the fee payer, to take its token once the primary accepts the transaction.
+            let payer = fee_payer(&transaction);
             // Send the unconfirmed transaction to the primary.
-            if let Err(e) =
-                self.primary_sender().send_unconfirmed_transaction(transaction_id, Data::Object(transaction)).await
-            {
-                // If the BFT is synced, then log the warning.
-                if self.bft.is_synced() {
+            let result = match deadline {
+                Some(deadline) => {
+                    let timeout = deadline.saturating_duration_since(Instant::now());
+                    match primary_sender
+                        .send_unconfirmed_transaction_with_timeout(transaction_id, Data::Object(transaction), timeout)
+                        .await
+                    {
+                        // If the primary channel is full, return the unsent transactions to the inbound queue.
+                        Ok(Some(transaction)) => {
+                            let transaction = transaction.deserialize_blocking().map_err(ConsensusError::BftError)?;
+                            self.requeue_unconfirmed_transactions(std::iter::once(transaction).chain(transactions));
+                            return Err(ConsensusError::Backpressure);
+                        }
+                        Ok(None) => Ok(()),
+                        Err(e) => Err(e),
+                    }
+                }
+                None => primary_sender.send_unconfirmed_transaction(transaction_id, Data::Object(transaction)).await,
+            };
+            match result {
+                Ok(()) => {
+                    num_accepted += 1;
+                    self.charge_rate_limit(payer);
+                    self.transaction_events.send(TransactionEvent::Accepted(transaction_id)).ok();
+                    // Notify the subscribers, now that the primary has accepted the transaction.
+                    if let Some(transaction) = new_transaction {
+                        self.new_transactions.send(transaction).ok();
+                    }
+                }
+                Err(e) => {
+                    // If the BFT is synced, then log the warning.
+                    if self.bft.is_synced() {
+                        warn!(
+                            target: MEMPOOL_TARGET,
+                            "Failed to add unconfirmed transaction '{}' to the memory pool - {e}",
+                            fmt_id(transaction_id)
+                        );
+                    }
+                    self.reject_unconfirmed_transaction(transaction_id, e.to_string());
+                }
+            }
+        }
+        Ok(num_accepted)
+    }
+
+    /// Returns the given transactions to the inbound queue, after the primary failed to accept them in time.
+    fn requeue_unconfirmed_transactions(&self, transactions: impl Iterator<Item = Transaction<N>>) {
+        let mut tx_queue = self.transactions_queue.lock();
+        for transaction in transactions {
+            let transaction_id = transaction.id();
+            match tx_queue.insert(transaction_id, transaction) {
+                Ok(Some(evicted_id)) => {
+                    debug!(target: MEMPOOL_TARGET, "Evicted transaction '{}' from the full queue", fmt_id(evicted_id));
+                    self.transaction_events.send(TransactionEvent::Dropped(evicted_id, DropReason::Evicted)).ok();
+                }
+                Ok(None) => (),
+                Err(e) => {
+                    trace!(target: MEMPOOL_TARGET, "Skipped requeueing transaction '{}' - {e}", fmt_id(transaction_id))
+                }
+            }
+        }
+    }
+
+    /// Removes the given unconfirmed transaction from the memory pool.
+    /// Returns `true` if the transaction was found and removed, and `false` otherwise.
+    ///
+    /// Note: Transactions that are already part of a proposed or certified batch are not removed.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions, transaction_id = %transaction_id))]
+    pub fn remove_unconfirmed_transaction(&self, transaction_id: N::TransactionID) -> bool {
+        self.record_span_fields();
+        // Forget the transaction, so that a resubmission is not skipped.
+        self.seen_transactions.lock().pop(&transaction_id);
+        // Remove the transaction from the inbound queue, and the ready queues of the workers.
+        let is_removed_from_queue = self.transactions_queue.lock().remove(&transaction_id);
+        let is_removed_from_bft = self.bft.remove_unconfirmed_transaction(&transaction_id);
+        // Log the removal.
+        let is_removed = is_removed_from_queue || is_removed_from_bft;
+        if is_removed {
+            debug!(
+                target: MEMPOOL_TARGET,
+                "Removed unconfirmed transaction '{}' from the memory pool",
+                fmt_id(transaction_id)
+            );
+            self.transaction_events.send(TransactionEvent::Dropped(transaction_id, DropReason::Removed)).ok();
+        }
+        is_removed
+    }
+
+    /// Evicts the given unconfirmed transaction from the inbound queue and the memory pool, on behalf of the operator.
+    /// Returns `true` if the transaction was found and removed, and `false` otherwise.
+    ///
+    /// The transaction is rejected with `ConsensusError::TransactionEvicted` if it is resubmitted within
+    /// `EVICTION_COOLDOWN`, even if it was not found.
+    /// Note: Transactions that are already part of a proposed or certified batch are not removed.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions, transaction_id = %transaction_id))]
+    pub fn evict_transaction(&self, transaction_id: N::TransactionID) -> bool {
+        self.record_span_fields();
+        // Prevent the transaction from re-entering the memory pool.
+        self.evicted_transactions.lock().put(transaction_id, Instant::now());
+        // Forget the transaction, so that a resubmission after the cooldown is not skipped.
+        self.seen_transactions.lock().pop(&transaction_id);
+        // Remove the transaction from the inbound queue, and the ready queues of the workers.
+        let is_removed_from_queue = self.transactions_queue.lock().remove(&transaction_id);
+        let is_removed_from_bft = self.bft.remove_unconfirmed_transaction(&transaction_id);
+        // Log the eviction.
+        let is_removed = is_removed_from_queue || is_removed_from_bft;
+        if is_removed {
+            debug!(
+                target: MEMPOOL_TARGET,
+                "Evicted unconfirmed transaction '{}' from the memory pool",
+                fmt_id(transaction_id)
+            );
+            self.transaction_events.send(TransactionEvent::Dropped(transaction_id, DropReason::ManuallyEvicted)).ok();
+        }
+        is_removed
+    }
+
+    /// Removes the unconfirmed transactions whose fee is paid publicly by the given address, from the inbound queue
+    /// and the memory pool, and returns the number of transactions removed.
+    ///
+    /// Note: A transaction only reveals its fee payer if the fee is public, so transactions with a private fee
+    /// are never matched, and the signer of an execution may differ from its fee payer. The transactions are not
+    /// indexed by fee payer, so this scans the memory pool. Transactions that are already part of a proposed
+    /// or certified batch are not removed.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions, address = %address))]
+    pub fn clear_transactions_by_sender(&self, address: &Address<N>) -> usize {
+        self.record_span_fields();
+        let is_paid_by = |transaction: &Transaction<N>| fee_payer(transaction).is_some_and(|payer| payer == *address);
+        // Select the matching transactions in the inbound queue.
+        let mut transaction_ids = {
+            let tx_queue = self.transactions_queue.lock();
+            tx_queue
+                .deployments
+                .iter()
+                .chain(tx_queue.executions.iter())
+                .filter(|(_, entry)| is_paid_by(&entry.transaction))
+                .map(|(transaction_id, _)| *transaction_id)
+                .collect::<Vec<_>>()
+        };
+        // Select the matching transactions in the memory pool.
+        for (transaction_id, transaction) in self.unconfirmed_transactions() {
+            match transaction.deserialize_blocking() {
+                Ok(transaction) if is_paid_by(&transaction) => transaction_ids.push(transaction_id),
+                Ok(_) => (),
+                Err(e) => {
                     warn!(
-                        "Failed to add unconfirmed transaction '{}' to the memory pool - {e}",
+                        target: MEMPOOL_TARGET,
+                        "Failed to deserialize unconfirmed transaction '{}' - {e}",
                         fmt_id(transaction_id)
                     );
                 }
             }
         }
+        // Remove the transactions, which notifies the subscribers of each removal.
+        let num_removed = transaction_ids
+            .into_iter()
+            .filter(|transaction_id| self.remove_unconfirmed_transaction(*transaction_id))
+            .count();
+        debug!(target: MEMPOOL_TARGET, "Removed {num_removed} unconfirmed transaction(s) paid by '{address}'");
+        num_removed
+    }
+
+    /// Removes and returns all the unconfirmed transactions and solutions, from the inbound queues and the memory pool.
+    ///
+    /// Note: The inbound queues are locked for the duration of the drain, so nothing is queued in between.
+    /// Transmissions that peers send to the workers concurrently may still arrive after the drain.
+    /// Transmissions that are already part of a proposed or certified batch are not removed.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions))]
+    pub fn drain_memory_pool(&self) -> (Vec<Transaction<N>>, Vec<Solution<N>>) {
+        self.record_span_fields();
+        // Lock the proof target cache and the inbound queues, in this order.
+        let mut proof_target_cache = self.proof_target_cache.lock();
+        let mut solutions_queue = self.solutions_queue.lock();
+        let mut tx_queue = self.transactions_queue.lock();
+        // Drain the inbound queues.
+        let mut solutions =
+            std::iter::from_fn(|| solutions_queue.pop_lru()).map(|(_, solution)| solution).collect_vec();
+        let mut transactions = tx_queue.drain();
+        // Stop tracking the drained solutions.
+        proof_target_cache.clear_solutions();
+        #[cfg(feature = "metrics")]
+        {
+            metrics::counter(metrics::memory_pool::SOLUTIONS_REMOVED, solutions.len() as u64);
+            metrics::gauge(metrics::memory_pool::SOLUTIONS_CURRENT, 0f64);
+        }
+        // Drain the ready queues of the workers.
+        for (transmission_id, transmission) in self.bft.drain_unconfirmed_transmissions() {
+            let result = match transmission {
+                Transmission::Solution(solution) => solution.deserialize_blocking().map(|s| solutions.push(s)),
+                Transmission::Transaction(transaction) => {
+                    transaction.deserialize_blocking().map(|t| transactions.push(t))
+                }
+                Transmission::Ratification => Ok(()),
+            };
+            if let Err(e) = result {
+                warn!(
+                    target: MEMPOOL_TARGET,
+                    "Failed to deserialize drained transmission '{}' - {e}",
+                    fmt_id(transmission_id)
+                );
+            }
+        }
+        // Notify the subscribers of the removed transactions.
+        for transaction in &transactions {
+            self.transaction_events.send(TransactionEvent::Dropped(transaction.id(), DropReason::Removed)).ok();
+        }
+        debug!(
+            target: MEMPOOL_TARGET,
+            "Drained {} transaction(s) and {} solution(s) from the memory pool",
+            transactions.len(),
+            solutions.len()
+        );
+        (transactions, solutions)
+    }
+}
+
+impl<N: Network> Consensus<N> {
+    /// Writes a snapshot of the unconfirmed solutions and transactions to the given path.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions, path = %path.display()))]
+    pub fn persist_to(&self, path: &Path) -> Result<(), ConsensusError> {
+        self.record_span_fields();
+        let snapshot = self.export_mempool_snapshot()?;
+        Self::write_snapshot_file(path, &snapshot)
+    }
+
+    /// Writes the given snapshot to a temporary file, and renames it into place,
+    /// so that a crash during the write does not leave a truncated snapshot behind.
+    fn write_snapshot_file(path: &Path, snapshot: &[u8]) -> Result<(), ConsensusError> {
+        let temp_path = path.with_extension("tmp");
+        std::fs::write(&temp_path, snapshot)?;
+        std::fs::rename(&temp_path, path)?;
         Ok(())
     }
+
+    /// Reloads the unconfirmed solutions and transactions from the snapshot at the given path,
+    /// returning the number of entries that were added back to the memory pool.
+    ///
+    /// Note: Each entry is re-validated against the current ledger, so confirmed or stale entries are dropped.
+    /// The snapshot is deleted once it is loaded, so that it is not reloaded after a later crash. If any entries
+    /// are skipped for a transient reason, such as a full memory pool, the snapshot is rewritten with those entries.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions, path = %path.display()))]
+    pub async fn load_from(&self, path: &Path) -> Result<usize, ConsensusError> {
+        self.record_span_fields();
+        // If there is no snapshot, there is nothing to load.
+        let snapshot = match std::fs::read(path) {
+            Ok(snapshot) => snapshot,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e.into()),
+        };
+        let (num_loaded, (solutions, transactions)) = self.import_mempool_entries(&snapshot).await?;
+        // Remove the snapshot, or keep the entries that may still be added on a later load.
+        if solutions.is_empty() && transactions.is_empty() {
+            std::fs::remove_file(path)?;
+        } else {
+            warn!(
+                target: MEMPOOL_TARGET,
+                "Kept {} solution(s) and {} transaction(s) in '{}', as they could not be added yet",
+                solutions.len(),
+                transactions.len(),
+                path.display()
+            );
+            let remainder =
+                Self::try_write_mempool_snapshot(&solutions, &transactions).map_err(ConsensusError::BftError)?;
+            Self::write_snapshot_file(path, &remainder)?;
+        }
+        Ok(num_loaded)
+    }
+
+    /// Serializes the unconfirmed solutions and transactions, in the inbound queues and the memory pool.
+    ///
+    /// Note: The entries are sorted by their serialization, so the snapshot does not depend on the
+    /// order in which the entries were received.
+    pub fn export_mempool_snapshot(&self) -> Result<Vec<u8>, ConsensusError> {
+        self.try_export_mempool_snapshot().map_err(ConsensusError::BftError)
+    }
+
+    /// Serializes the unconfirmed solutions and transactions, sorted by their serialization.
+    fn try_export_mempool_snapshot(&self) -> Result<Vec<u8>> {
+        // Serialize the unconfirmed solutions.
+        let mut solutions =
+            self.solutions_queue.lock().iter().map(|(_, s)| s.to_bytes_le()).collect::<Result<Vec<_>>>()?;
+        for (_, solution) in self.unconfirmed_solutions() {
+            solutions.push(solution.deserialize_blocking()?.to_bytes_le()?);
+        }
+        // Serialize the unconfirmed transactions.
+        let mut transactions = {
+            let tx_queue = self.transactions_queue.lock();
+            tx_queue
+                .deployments
+                .iter()
+                .chain(tx_queue.executions.iter())
+                .map(|(_, entry)| entry.transaction.to_bytes_le())
+                .collect::<Result<Vec<_>>>()?
+        };
+        for (_, transaction) in self.unconfirmed_transactions() {
+            transactions.push(transaction.deserialize_blocking()?.to_bytes_le()?);
+        }
+        Self::write_mempool_snapshot(solutions, transactions)
+    }
+
+    /// Serializes the given solutions and transactions into a snapshot.
+    fn try_write_mempool_snapshot(solutions: &[Solution<N>], transactions: &[Transaction<N>]) -> Result<Vec<u8>> {
+        let solutions = solutions.iter().map(|solution| solution.to_bytes_le()).collect::<Result<Vec<_>>>()?;
+        let transactions =
+            transactions.iter().map(|transaction| transaction.to_bytes_le()).collect::<Result<Vec<_>>>()?;
+        Self::write_mempool_snapshot(solutions, transactions)
+    }
+
+    /// Writes the given serialized solutions and transactions into a snapshot, sorted by their serialization.
+    fn write_mempool_snapshot(mut solutions: Vec<Vec<u8>>, mut transactions: Vec<Vec<u8>>) -> Result<Vec<u8>> {
+        // Sort the entries.
+        solutions.sort_unstable();
+        transactions.sort_unstable();
+
+        // Write the snapshot.
+        let mut bytes = Vec::new();
+        u32::try_from(solutions.len())?.write_le(&mut bytes)?;
+        solutions.iter().for_each(|solution| bytes.extend_from_slice(solution));
+        u32::try_from(transactions.len())?.write_le(&mut bytes)?;
+        transactions.iter().for_each(|transaction| bytes.extend_from_slice(transaction));
+        Ok(bytes)
+    }
+
+    /// Deserializes the given snapshot, and adds its solutions and transactions to the memory pool,
+    /// returning the number of entries that were accepted.
+    ///
+    /// Note: Each entry is re-validated against the current ledger state, and discarded if it is now invalid.
+    /// Entries that are skipped for a transient reason, such as a full memory pool, are logged and not retried.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions, num_bytes = bytes.len()))]
+    pub async fn import_mempool_snapshot(&self, bytes: &[u8]) -> Result<usize, ConsensusError> {
+        self.record_span_fields();
+        let (num_imported, (solutions, transactions)) = self.import_mempool_entries(bytes).await?;
+        if !solutions.is_empty() || !transactions.is_empty() {
+            warn!(
+                target: MEMPOOL_TARGET,
+                "Skipped {} solution(s) and {} transaction(s) from the snapshot, as they could not be added yet",
+                solutions.len(),
+                transactions.len()
+            );
+        }
+        Ok(num_imported)
+    }
+
+    /// Deserializes the given snapshot, and adds its solutions and transactions to the memory pool,
+    /// returning the number of entries that were accepted, and the entries that were skipped for a transient reason.
+    async fn import_mempool_entries(
+        &self,
+        bytes: &[u8],
+    ) -> Result<(usize, (Vec<Solution<N>>, Vec<Transaction<N>>)), ConsensusError> {
+        // Parse the snapshot.
+        let (solutions, transactions) =
+            Self::parse_mempool_snapshot(bytes).map_err(|e| ConsensusError::InvalidSnapshot(e.to_string()))?;
+
+        // Add the solutions and transactions to the memory pool.
+        let mut num_imported = 0;
+        let (mut skipped_solutions, mut skipped_transactions) = (Vec::new(), Vec::new());
+        for solution in solutions {
+            let solution_id = solution.id();
+            // Note: The solution is cloned, so that it can be kept if it is skipped.
+            match self.add_unconfirmed_solution(solution.clone()).await {
+                Ok(()) => num_imported += 1,
+                Err(e) if e.is_transient() => {
+                    debug!(
+                        target: MEMPOOL_TARGET,
+                        "Skipped solution '{}' from the snapshot - {e}",
+                        fmt_id(solution_id)
+                    );
+                    skipped_solutions.push(solution);
+                }
+                Err(e) => {
+                    debug!(
+                        target: MEMPOOL_TARGET,
+                        "Discarded solution '{}' from the snapshot - {e}",
+                        fmt_id(solution_id)
+                    )
+                }
+            }
+        }
+        for transaction in transactions {
+            let transaction_id = transaction.id();
+            // Note: The transaction is cloned, so that it can be kept if it is skipped.
+            match self.add_unconfirmed_transaction(transaction.clone()).await {
+                Ok(()) => num_imported += 1,
+                Err(e) if e.is_transient() => {
+                    debug!(
+                        target: MEMPOOL_TARGET,
+                        "Skipped transaction '{}' from the snapshot - {e}",
+                        fmt_id(transaction_id)
+                    );
+                    skipped_transactions.push(transaction);
+                }
+                Err(e) => {
+                    debug!(
+                        target: MEMPOOL_TARGET,
+                        "Discarded transaction '{}' from the snapshot - {e}",
+                        fmt_id(transaction_id)
+                    )
+                }
+            }
+        }
+        Ok((num_imported, (skipped_solutions, skipped_transactions)))
+    }
+
+    /// Deserializes the solutions and transactions in the given snapshot.
+    fn parse_mempool_snapshot(bytes: &[u8]) -> Result<(Vec<Solution<N>>, Vec<Transaction<N>>)> {
+        let mut reader = bytes;
+        // Read the solutions.
+        let num_solutions = u32::read_le(&mut reader)?;
+        let solutions =
+            (0..num_solutions).map(|_| Solution::<N>::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Read the transactions.
+        let num_transactions = u32::read_le(&mut reader)?;
+        let transactions =
+            (0..num_transactions).map(|_| Transaction::<N>::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Ensure the snapshot has no trailing bytes.
+        ensure!(reader.is_empty(), "Memory pool snapshot has {} trailing bytes", reader.len());
+        Ok((solutions, transactions))
+    }
+}
+
+impl<N: Network> Consensus<N> {
+    /// Validates and applies the given blocks in order, returning the number of blocks applied.
+    /// On failure, the blocks before the failing block remain applied.
+    ///
+    /// Note: This path is intended for the initial sync, so it does not clear the memory pool or emit transaction
+    /// events, as there are no live transactions to track. The coinbase target is still re-evaluated, and the block
+    /// subscribers are still notified, after each block.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions))]
+    pub async fn catchup(&self, blocks: impl IntoIterator<Item = Block<N>>) -> Result<usize, CatchupError> {
+        self.record_span_fields();
+        let blocks = blocks.into_iter().collect::<Vec<_>>();
+        // Retrieve the starting height, to determine the number of blocks applied on failure.
+        let start_height = self.ledger.latest_block_height();
+        // Apply the blocks.
+        let self_ = self.clone();
+        match tokio::task::spawn_blocking(move || self_.try_catchup(blocks)).await {
+            Ok(result) => result,
+            Err(error) => {
+                let latest_height = self.ledger.latest_block_height();
+                Err(CatchupError::Storage {
+                    height: latest_height.saturating_add(1),
+                    num_applied: latest_height.saturating_sub(start_height) as usize,
+                    reason: format!("[tokio::spawn_blocking] {error}"),
+                })
+            }
+        }
+    }
+
+    /// Adds the given block hash as a known good checkpoint, from which catchup may start.
+    pub fn add_checkpoint(&self, height: u32, hash: N::BlockHash) {
+        self.checkpoints.write().insert(height, hash);
+    }
+
+    /// Validates and applies the given blocks after a trusted checkpoint, returning the latest block height.
+    ///
+    /// The checkpoint must be a known checkpoint (see `add_checkpoint`), and must match the block in the ledger
+    /// at the checkpoint height. Blocks that are already in the ledger are skipped.
+    #[instrument(
+        level = "debug",
+        skip_all,
+        fields(latest_height, num_transmissions, checkpoint_height = checkpoint_height)
+    )]
+    pub async fn catchup_from_checkpoint(
+        &self,
+        checkpoint_height: u32,
+        checkpoint_hash: N::BlockHash,
+        blocks: impl IntoIterator<Item = Block<N>>,
+    ) -> Result<u32, CatchupError> {
+        self.record_span_fields();
+        // Ensure the checkpoint is a known checkpoint.
+        match self.checkpoints.read().get(&checkpoint_height) {
+            Some(hash) if *hash == checkpoint_hash => (),
+            Some(hash) => {
+                let reason = format!("expected '{hash}', found '{checkpoint_hash}'");
+                return Err(CatchupError::CheckpointMismatch { height: checkpoint_height, reason });
+            }
+            None => return Err(CatchupError::UnknownCheckpoint { height: checkpoint_height }),
+        }
+        // Ensure the ledger contains the checkpoint.
+        let reason = match self.ledger.get_block_hash(checkpoint_height) {
+            Ok(hash) if hash == checkpoint_hash => None,
+            Ok(hash) => Some(format!("expected '{checkpoint_hash}', the ledger has '{hash}'")),
+            Err(e) => Some(e.to_string()),
+        };
+        if let Some(reason) = reason {
+            return Err(CatchupError::CheckpointMismatch { height: checkpoint_height, reason });
+        }
+        // Apply the blocks after the latest block in the ledger.
+        let latest_height = self.ledger.latest_block_height();
+        self.catchup(blocks.into_iter().skip_while(|block| block.height() <= latest_height)).await?;
+        Ok(self.ledger.latest_block_height())
+    }
+
+    /// Validates and applies the given blocks in order, stopping at the first failure.
+    fn try_catchup(&self, blocks: Vec<Block<N>>) -> Result<usize, CatchupError> {
+        let num_blocks = blocks.len();
+        for (num_applied, block) in blocks.into_iter().enumerate() {
+            let height = block.height();
+            // Ensure the block is a valid next block.
+            if let Err(e) = self.verify_block(&block) {
+                return Err(CatchupError::InvalidBlock { height, num_applied, reason: e.to_string() });
+            }
+            // Advance to the next block.
+            if let Err(e) = self.ledger.advance_to_next_block(&block) {
+                return Err(CatchupError::Storage { height, num_applied, reason: e.to_string() });
+            }
+            trace!(target: BLOCK_TARGET, "Applied block {height} during catchup");
+            // Check if the coinbase target is met, now that the block has been advanced.
+            self.reset_proof_target_cache(&block);
+            self.update_coinbase_ready();
+            // Notify the subscribers of the new block.
+            self.notify_new_block(&block);
+        }
+        Ok(num_blocks)
+    }
 }
 
 impl<N: Network> Consensus<N> {
@@ -364,11 +2286,36 @@ impl<N: Network> Consensus<N> {
 
         // Process the committed subdag and transmissions from the BFT.
         let self_ = self.clone();
-        self.spawn(async move {
+        self.spawn("bft_subdags", async move {
             while let Some((committed_subdag, transmissions, callback)) = rx_consensus_subdag.recv().await {
                 self_.process_bft_subdag(committed_subdag, transmissions, callback).await;
             }
         });
+
+        // Periodically expire the transactions that exceed the TTL, if one is configured.
+        if let Some(ttl) = self.config.transaction_ttl {
+            let self_ = self.clone();
+            let interval = self.config.expiry_sweep_interval();
+            self.spawn("transaction_expiry", async move {
+                loop {
+                    tokio::time::sleep(interval).await;
+                    self_.expire_transactions(ttl);
+                }
+            });
+        }
+    }
+
+    /// Removes the transactions that have waited in the inbound queue for at least the given TTL,
+    /// returning the number of expired transactions.
+    ///
+    /// Note: Transactions that were already sent to the primary do not expire, as they may be part of a batch.
+    fn expire_transactions(&self, ttl: Duration) -> usize {
+        let transactions = self.transactions_queue.lock().pop_older_than(ttl, usize::MAX);
+        for transaction in &transactions {
+            debug!(target: MEMPOOL_TARGET, "Expired transaction '{}' from the queue", fmt_id(transaction.id()));
+            self.transaction_events.send(TransactionEvent::Dropped(transaction.id(), DropReason::Expired)).ok();
+        }
+        transactions.len()
     }
 
     /// Processes the committed subdag and transmissions from the BFT.
@@ -378,41 +2325,144 @@ impl<N: Network> Consensus<N> {
         transmissions: IndexMap<TransmissionID<N>, Transmission<N>>,
         callback: oneshot::Sender<Result<()>>,
     ) {
+        // Track the subdag as in-flight, until the callback is sent.
+        self.num_in_flight_subdags.fetch_add(1, Ordering::SeqCst);
+        let round = subdag.anchor_round();
+        trace!(
+            target: SUBDAG_TARGET,
+            "Received the committed subdag for round {round} ({} transmissions)",
+            transmissions.len()
+        );
+        // Note: The subdag is only cloned if there is a subscriber, and sending fails only if there is none.
+        if self.committed_subdags.receiver_count() > 0 {
+            self.committed_subdags.send((subdag.clone(), transmissions.clone())).ok();
+        }
         // Try to advance to the next block.
-        let self_ = self.clone();
-        let transmissions_ = transmissions.clone();
-        let result = spawn_blocking! { self_.try_advance_to_next_block(subdag, transmissions_) };
+        let span = debug_span!(target: SUBDAG_TARGET, "process_bft_subdag", round);
+        let result = self.advance_to_next_block_with_retries(subdag, &transmissions).instrument(span).await;
 
         // If the block failed to advance, reinsert the transmissions into the memory pool.
         if let Err(e) = &result {
-            error!("Unable to advance to the next block - {e}");
+            error!(target: SUBDAG_TARGET, "Unable to advance to the next block - {e}");
             // On failure, reinsert the transmissions into the memory pool.
             self.reinsert_transmissions(transmissions).await;
         }
         // Send the callback **after** advancing to the next block.
         // Note: We must await the block to be advanced before sending the callback.
-        callback.send(result).ok();
+        callback.send(result.map_err(|e| e.into())).ok();
+        self.num_in_flight_subdags.fetch_sub(1, Ordering::SeqCst);
+
+        #[cfg(feature = "metrics")]
+        {
+            // Sync the unconfirmed gauges with the memory pool, now that the committed transmissions are removed.
+            metrics::gauge(metrics::consensus::UNCONFIRMED_SOLUTIONS, self.num_unconfirmed_solutions() as f64);
+            metrics::gauge(metrics::consensus::UNCONFIRMED_TRANSACTIONS, self.num_unconfirmed_transactions() as f64);
+            metrics::gauge(metrics::consensus::UNCONFIRMED_TRANSMISSIONS, self.num_unconfirmed_transmissions() as f64);
+        }
     }
 
-    /// Attempts to advance to the next block.
-    fn try_advance_to_next_block(
+    /// Attempts to advance to the next block, retrying transient failures with an exponential backoff.
+    ///
+    /// Note: The block is prepared and checked once, as only storing the block may fail transiently.
+    async fn advance_to_next_block_with_retries(
         &self,
         subdag: Subdag<N>,
+        transmissions: &IndexMap<TransmissionID<N>, Transmission<N>>,
+    ) -> Result<(), AdvanceError> {
+        // Prepare the next block.
+        let subdag = Arc::new(subdag);
+        let (self_, subdag_, transmissions) = (self.clone(), subdag.clone(), transmissions.clone());
+        let handle = tokio::task::spawn_blocking(move || self_.prepare_next_block(&subdag_, transmissions));
+        let next_block = match handle.await {
+            Ok(result) => Arc::new(result?),
+            Err(error) => return Err(AdvanceError::InvalidBlock(format!("[tokio::spawn_blocking] {error}"))),
+        };
+        // Advance to the next block.
+        let mut attempt = 1;
+        loop {
+            let (self_, subdag, next_block) = (self.clone(), subdag.clone(), next_block.clone());
+            let handle = tokio::task::spawn_blocking(move || self_.try_advance_to_next_block(&subdag, &next_block));
+            let result = match handle.await {
+                Ok(result) => result,
+                Err(error) => Err(AdvanceError::InvalidBlock(format!("[tokio::spawn_blocking] {error}"))),
+            };
+            match result {
+                Err(e) if e.is_transient() && attempt < MAX_ADVANCE_ATTEMPTS => {
+                    let backoff = ADVANCE_RETRY_BACKOFF * 2u32.pow(attempt - 1);
+                    warn!(
+                        target: BLOCK_TARGET,
+                        "Retrying to advance to the next block in {backoff:?} (attempt {attempt}) - {e}"
+                    );
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Prepares the next block from the given subdag and transmissions, and checks that it is well-formed.
+    fn prepare_next_block(
+        &self,
+        subdag: &Subdag<N>,
         transmissions: IndexMap<TransmissionID<N>, Transmission<N>>,
-    ) -> Result<()> {
+    ) -> Result<Block<N>, AdvanceError> {
+        let _span = debug_span!(target: BLOCK_TARGET, "prepare_next_block", round = subdag.anchor_round()).entered();
+        // Create the candidate next block.
+        let next_block = self
+            .ledger
+            .prepare_advance_to_next_quorum_block(subdag.clone(), transmissions)
+            .map_err(|e| AdvanceError::InvalidBlock(e.to_string()))?;
+        // Check that the block is well-formed.
+        self.ledger.check_next_block(&next_block).map_err(|e| AdvanceError::InvalidBlock(e.to_string()))?;
+        Ok(next_block)
+    }
+
+    /// Attempts to advance to the given next block, which was prepared from the given subdag.
+    fn try_advance_to_next_block(&self, subdag: &Subdag<N>, next_block: &Block<N>) -> Result<(), AdvanceError> {
+        let _span = debug_span!(target: BLOCK_TARGET, "advance_to_next_block", round = subdag.anchor_round()).entered();
         #[cfg(feature = "metrics")]
         let start = subdag.leader_certificate().batch_header().timestamp();
         #[cfg(feature = "metrics")]
         let num_committed_certificates = subdag.values().map(|c| c.len()).sum::<usize>();
         #[cfg(feature = "metrics")]
+        let num_committed_transmissions_per_round = subdag
+            .values()
+            .map(|certificates| certificates.iter().map(|c| c.transmission_ids().len()).sum::<usize>())
+            .collect::<Vec<_>>();
+        #[cfg(feature = "metrics")]
         let current_block_timestamp = self.ledger.latest_block().header().metadata().timestamp();
 
-        // Create the candidate next block.
-        let next_block = self.ledger.prepare_advance_to_next_quorum_block(subdag, transmissions)?;
-        // Check that the block is well-formed.
-        self.ledger.check_next_block(&next_block)?;
         // Advance to the next block.
-        self.ledger.advance_to_next_block(&next_block)?;
+        if let Err(e) = self.ledger.advance_to_next_block(next_block) {
+            // Note: The ledger does not change if the block fails to be stored, so the failure may only be retried
+            // if the ledger is still at the parent of the block. Otherwise, the block was superseded, e.g. by sync.
+            return Err(match self.ledger.latest_block_hash() == next_block.previous_hash() {
+                true => AdvanceError::Storage(e.to_string()),
+                false => AdvanceError::InvalidBlock(e.to_string()),
+            });
+        }
+        // Check if the coinbase target is met, now that the block has been advanced.
+        self.reset_proof_target_cache(next_block);
+        self.update_coinbase_ready();
+        // Notify the subscribers of the included and aborted transactions.
+        for transaction_id in next_block.transactions().transaction_ids() {
+            self.transaction_events.send(TransactionEvent::IncludedInBlock(*transaction_id, next_block.height())).ok();
+        }
+        for transaction_id in next_block.aborted_transaction_ids() {
+            self.transaction_events.send(TransactionEvent::Dropped(*transaction_id, DropReason::Aborted)).ok();
+        }
+        // Clear the queued transactions which were invalidated by the block, and notify the subscribers.
+        for transaction_id in self.clear_invalid_transactions(next_block) {
+            debug!(
+                target: MEMPOOL_TARGET,
+                "Dropped conflicting transaction '{}' from the queue",
+                fmt_id(transaction_id)
+            );
+            self.transaction_events.send(TransactionEvent::Dropped(transaction_id, DropReason::Conflicted)).ok();
+        }
+        // Notify the subscribers of the new block.
+        self.notify_new_block(next_block);
 
         #[cfg(feature = "metrics")]
         {
@@ -431,17 +2481,61 @@ impl<N: Network> Consensus<N> {
             metrics::gauge(metrics::consensus::COMMITTED_CERTIFICATES, num_committed_certificates as f64);
             metrics::histogram(metrics::consensus::CERTIFICATE_COMMIT_LATENCY, elapsed.as_secs_f64());
             metrics::histogram(metrics::consensus::BLOCK_LATENCY, block_latency as f64);
+            for num_committed_transmissions in num_committed_transmissions_per_round {
+                metrics::histogram(
+                    metrics::consensus::COMMITTED_TRANSMISSIONS_PER_ROUND,
+                    num_committed_transmissions as f64,
+                );
+            }
         }
         Ok(())
     }
 
+    /// Removes the queued transactions which were confirmed in the given block, or which spend an input
+    /// that the block spent, and returns the IDs of the conflicting transactions.
+    ///
+    /// Note: The transactions in the memory pool are not cleared, as the workers remove them when they are proposed.
+    fn clear_invalid_transactions(&self, block: &Block<N>) -> Vec<N::TransactionID> {
+        let confirmed_ids = block.transaction_ids().copied().collect::<HashSet<_>>();
+        let confirmed_serial_numbers = block.transactions().serial_numbers().copied().collect::<HashSet<_>>();
+        self.transactions_queue.lock().remove_conflicts(&confirmed_ids, &confirmed_serial_numbers)
+    }
+
+    /// Notifies the subscribers of the given block, which the ledger has already advanced to.
+    fn notify_new_block(&self, block: &Block<N>) {
+        self.notify_new_block_at(block, block.timestamp())
+    }
+
+    /// Notifies the subscribers of the given block, which the ledger has already advanced to, at the given timestamp.
+    fn notify_new_block_at(&self, block: &Block<N>, timestamp: i64) {
+        self.record_block_timestamp(timestamp);
+        self.block_events.send(BlockAdvanced::from(block)).ok();
+        // Note: The block is only cloned if there is a subscriber, and sending fails only if there is none.
+        if self.new_blocks.receiver_count() > 0 {
+            self.new_blocks.send(block.clone()).ok();
+        }
+    }
+
+    /// Records the given block timestamp, retaining only the timestamps in the block time window.
+    fn record_block_timestamp(&self, timestamp: i64) {
+        let mut block_timestamps = self.block_timestamps.lock();
+        block_timestamps.push_back(UNIX_EPOCH + Duration::from_secs(timestamp.max(0) as u64));
+        while block_timestamps.len() > self.config.block_time_window() {
+            block_timestamps.pop_front();
+        }
+    }
+
     /// Reinserts the given transmissions into the memory pool.
     async fn reinsert_transmissions(&self, transmissions: IndexMap<TransmissionID<N>, Transmission<N>>) {
         // Iterate over the transmissions.
         for (transmission_id, transmission) in transmissions.into_iter() {
             // Reinsert the transmission into the memory pool.
             if let Err(e) = self.reinsert_transmission(transmission_id, transmission).await {
-                warn!("Unable to reinsert transmission {} into the memory pool - {e}", fmt_id(transmission_id));
+                warn!(
+                    target: MEMPOOL_TARGET,
+                    "Unable to reinsert transmission {} into the memory pool - {e}",
+                    fmt_id(transmission_id)
+                );
             }
         }
     }
@@ -459,11 +2553,11 @@ impl<N: Network> Consensus<N> {
             (TransmissionID::Ratification, Transmission::Ratification) => return Ok(()),
             (TransmissionID::Solution(solution_id), Transmission::Solution(solution)) => {
                 // Send the solution to the primary.
-                self.primary_sender().tx_unconfirmed_solution.send((solution_id, solution, callback)).await?;
+                self.primary_sender()?.tx_unconfirmed_solution.send((solution_id, solution, callback)).await?;
             }
             (TransmissionID::Transaction(transaction_id), Transmission::Transaction(transaction)) => {
                 // Send the transaction to the primary.
-                self.primary_sender().tx_unconfirmed_transaction.send((transaction_id, transaction, callback)).await?;
+                self.primary_sender()?.tx_unconfirmed_transaction.send((transaction_id, transaction, callback)).await?;
             }
             _ => bail!("Mismatching `(transmission_id, transmission)` pair in consensus"),
         }
@@ -471,17 +2565,1322 @@ impl<N: Network> Consensus<N> {
         callback_receiver.await?
     }
 
-    /// Spawns a task with the given future; it should only be used for long-running tasks.
-    fn spawn<T: Future<Output = ()> + Send + 'static>(&self, future: T) {
-        self.handles.lock().push(tokio::spawn(future));
+    /// Spawns a task with the given future under the given name; it should only be used for long-running tasks.
+    fn spawn<T: Future<Output = ()> + Send + 'static>(&self, name: &'static str, future: T) {
+        self.tasks.register(name, tokio::spawn(future));
+    }
+
+    /// Returns the registry of the spawned tasks.
+    pub fn tasks(&self) -> &TaskRegistry {
+        &self.tasks
     }
 
-    /// Shuts down the BFT.
+    /// Shuts down the BFT, waiting up to the configured shutdown timeout for the in-flight subdags.
     pub async fn shut_down(&self) {
+        self.shut_down_graceful(self.config.shutdown_timeout()).await
+    }
+
+    /// Shuts down the BFT, and waits up to the given timeout for the in-flight subdags to be advanced into blocks.
+    /// If the timeout elapses, the remaining tasks are aborted.
+    #[instrument(level = "debug", skip_all, fields(latest_height, num_transmissions))]
+    pub async fn shut_down_graceful(&self, timeout: Duration) {
+        self.record_span_fields();
         info!("Shutting down consensus...");
-        // Shut down the BFT.
+        // Stop accepting new transmissions.
+        self.is_shutting_down.store(true, Ordering::SeqCst);
+        // Shut down the BFT, so that no new subdags are committed.
         self.bft.shut_down().await;
+        // Wait for the in-flight subdags to be advanced into blocks.
+        let num_in_flight = self.num_in_flight_subdags.load(Ordering::SeqCst);
+        let drain = async {
+            while self.num_in_flight_subdags.load(Ordering::SeqCst) > 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        };
+        if tokio::time::timeout(timeout, drain).await.is_err() {
+            warn!("Timed out after {timeout:?} while draining the in-flight subdags");
+        }
+        // Log the number of drained and aborted subdags.
+        let num_aborted = self.num_in_flight_subdags.load(Ordering::SeqCst);
+        if num_in_flight > 0 {
+            info!(
+                target: SUBDAG_TARGET,
+                "Drained {} in-flight subdag(s), aborting {num_aborted}",
+                num_in_flight.saturating_sub(num_aborted)
+            );
+        }
+        // Persist the memory pool, if persistence is enabled.
+        if let Some(path) = &self.memory_pool_path {
+            match self.persist_to(path) {
+                Ok(()) => info!(target: MEMPOOL_TARGET, "Persisted the memory pool to '{}'", path.display()),
+                Err(e) => {
+                    warn!(target: MEMPOOL_TARGET, "Failed to persist the memory pool to '{}' - {e}", path.display())
+                }
+            }
+        }
         // Abort the tasks.
-        self.handles.lock().iter().for_each(|handle| handle.abort());
+        self.tasks.abort_all();
+    }
+}
+
+/// Returns the cumulative proof target at which the coinbase target is reported as met, given whether it was met.
+fn coinbase_ready_threshold(coinbase_target: u128, config: &ConsensusConfig, was_met: bool) -> u128 {
+    match was_met {
+        true => coinbase_target.saturating_sub(config.coinbase_target_tolerance),
+        false => coinbase_target.saturating_add(config.coinbase_target_margin),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkos_node_bft::helpers::{init_consensus_channels, init_primary_channels};
+    use snarkos_node_bft_ledger_service::MockLedgerService;
+    use snarkos_node_bft_storage_service::BFTMemoryService;
+    use snarkvm::{
+        console::types::Field,
+        ledger::{
+            block::Ratify,
+            ledger_test_helpers::{
+                sample_execution_transaction_with_fee,
+                sample_fee_public_transaction,
+                sample_genesis_block,
+                sample_genesis_private_key,
+            },
+            narwhal::{subdag::test_helpers::sample_subdag, BatchCertificate},
+            store::helpers::memory::ConsensusMemory,
+            Ledger,
+        },
+        prelude::{Rng, TestRng},
+    };
+
+    use async_trait::async_trait;
+    use mockall::mock;
+    use std::{ops::Range, sync::atomic::AtomicUsize};
+
+    type CurrentNetwork = snarkvm::prelude::MainnetV0;
+
+    mock! {
+        #[derive(Debug)]
+        Ledger<N: Network> {}
+        #[async_trait]
+        impl<N: Network> LedgerService<N> for Ledger<N> {
+            fn latest_round(&self) -> u64;
+            fn latest_block_height(&self) -> u32;
+            fn latest_block_hash(&self) -> N::BlockHash;
+            fn latest_block(&self) -> Block<N>;
+            fn latest_leader(&self) -> Option<(u64, Address<N>)>;
+            fn update_latest_leader(&self, round: u64, leader: Address<N>);
+            fn contains_block_height(&self, height: u32) -> bool;
+            fn get_block_height(&self, hash: &N::BlockHash) -> Result<u32>;
+            fn get_block_hash(&self, height: u32) -> Result<N::BlockHash>;
+            fn get_block_round(&self, height: u32) -> Result<u64>;
+            fn get_block(&self, height: u32) -> Result<Block<N>>;
+            fn get_blocks(&self, heights: Range<u32>) -> Result<Vec<Block<N>>>;
+            fn find_block_hash(&self, transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>>;
+            fn get_solution(&self, solution_id: &SolutionID<N>) -> Result<Solution<N>>;
+            fn get_proof_target(&self, solution: &Solution<N>) -> Result<u64>;
+            fn get_unconfirmed_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>>;
+            fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<BatchCertificate<N>>;
+            fn current_committee(&self) -> Result<Committee<N>>;
+            fn get_committee_for_round(&self, round: u64) -> Result<Committee<N>>;
+            fn get_committee_lookback_for_round(&self, round: u64) -> Result<Committee<N>>;
+            fn contains_certificate(&self, certificate_id: &Field<N>) -> Result<bool>;
+            fn contains_transmission(&self, transmission_id: &TransmissionID<N>) -> Result<bool>;
+            fn ensure_transmission_is_well_formed(
+                &self,
+                transmission_id: TransmissionID<N>,
+                transmission: &mut Transmission<N>,
+            ) -> Result<()>;
+            async fn check_solution_basic(
+                &self,
+                solution_id: SolutionID<N>,
+                solution: Data<Solution<N>>,
+            ) -> Result<()>;
+            async fn check_transaction_basic(
+                &self,
+                transaction_id: N::TransactionID,
+                transaction: Data<Transaction<N>>,
+            ) -> Result<()>;
+            fn check_next_block(&self, block: &Block<N>) -> Result<()>;
+            fn prepare_advance_to_next_quorum_block(
+                &self,
+                subdag: Subdag<N>,
+                transmissions: IndexMap<TransmissionID<N>, Transmission<N>>,
+            ) -> Result<Block<N>>;
+            fn advance_to_next_block(&self, block: &Block<N>) -> Result<()>;
+            fn fork_at(&self, height: u32) -> Result<Arc<dyn LedgerService<N>>>;
+        }
+    }
+
+    /// Initializes a consensus instance backed by the given mock ledger and in-memory transmissions.
+    fn sample_consensus(mut ledger: MockLedger<CurrentNetwork>, rng: &mut TestRng) -> Consensus<CurrentNetwork> {
+        // Sample a committee.
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        ledger.expect_current_committee().returning(move || Ok(committee.clone()));
+        // Initialize the consensus.
+        sample_consensus_with(Arc::new(ledger), ConsensusConfig::default(), rng)
+    }
+
+    /// Initializes a consensus instance backed by the given ledger and in-memory transmissions, with the given config.
+    fn sample_consensus_with(
+        ledger: Arc<dyn LedgerService<CurrentNetwork>>,
+        config: ConsensusConfig,
+        rng: &mut TestRng,
+    ) -> Consensus<CurrentNetwork> {
+        let transmissions = Arc::new(BFTMemoryService::new());
+        Consensus::with_transmissions(
+            Account::new(rng).unwrap(),
+            ledger,
+            transmissions,
+            None,
+            &[],
+            StorageMode::Production,
+            config,
+        )
+        .unwrap()
+    }
+
+    /// Sets the primary sender of the given consensus,
+    /// and spawns a primary which accepts every unconfirmed transaction.
+    fn accept_unconfirmed_transactions(consensus: &Consensus<CurrentNetwork>) {
+        verify_unconfirmed_transactions(consensus, |_| Ok(()));
+    }
+
+    /// Sets the primary sender of the given consensus,
+    /// and spawns a primary which accepts the unconfirmed transactions that pass the given verification.
+    fn verify_unconfirmed_transactions(
+        consensus: &Consensus<CurrentNetwork>,
+        verify: impl Fn(<CurrentNetwork as Network>::TransactionID) -> Result<()> + Send + 'static,
+    ) {
+        let (primary_sender, mut primary_receiver) = init_primary_channels();
+        consensus.primary_sender.set(primary_sender).unwrap();
+        tokio::spawn(async move {
+            while let Some((transaction_id, _, callback)) = primary_receiver.rx_unconfirmed_transaction.recv().await {
+                callback.send(verify(transaction_id)).ok();
+            }
+        });
+    }
+
+    #[tokio::test]
+    async fn test_check_transaction_rejects_malformed_transaction() {
+        let rng = &mut TestRng::default();
+        // Setup the mock ledger.
+        let mut ledger = MockLedger::default();
+        ledger.expect_contains_transmission().returning(|_| Ok(false));
+        ledger.expect_check_transaction_basic().returning(|_, _| Err(anyhow!("Invalid proof")));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure a standalone fee transaction is rejected.
+        let transaction = sample_fee_public_transaction(rng);
+        assert!(matches!(consensus.check_transaction(&transaction).await, Err(ConsensusError::TransactionInvalid(_))));
+        // Ensure a transaction that fails verification is rejected.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        assert!(matches!(consensus.check_transaction(&transaction).await, Err(ConsensusError::TransactionInvalid(_))));
+        // Ensure the check does not add the transaction to the memory pool.
+        assert_eq!(consensus.num_queued_and_unconfirmed_transactions(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_check_transaction_rejects_double_spend() {
+        let rng = &mut TestRng::default();
+        // Setup the mock ledger, which reports the inputs of the transaction as already spent.
+        let mut ledger = MockLedger::default();
+        ledger.expect_contains_transmission().returning(|_| Ok(false));
+        ledger.expect_check_transaction_basic().returning(|_, _| Err(anyhow!("Found a duplicate serial number")));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the double-spend is rejected.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        assert!(matches!(consensus.check_transaction(&transaction).await, Err(ConsensusError::TransactionInvalid(_))));
+        assert_eq!(consensus.num_queued_and_unconfirmed_transactions(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_check_transaction_rejects_confirmed_transaction() {
+        let rng = &mut TestRng::default();
+        // Setup the mock ledger, which already contains the transaction.
+        let mut ledger = MockLedger::default();
+        ledger.expect_contains_transmission().returning(|_| Ok(true));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the duplicate is rejected, before it is verified.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        assert!(matches!(
+            consensus.check_transaction(&transaction).await,
+            Err(ConsensusError::TransactionAlreadyExists(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_check_transaction_ok() {
+        let rng = &mut TestRng::default();
+        // Setup the mock ledger.
+        let mut ledger = MockLedger::default();
+        ledger.expect_contains_transmission().returning(|_| Ok(false));
+        ledger.expect_check_transaction_basic().returning(|_, _| Ok(()));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure a valid transaction passes, without being added to the memory pool.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        assert!(consensus.check_transaction(&transaction).await.is_ok());
+        assert_eq!(consensus.num_queued_and_unconfirmed_transactions(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_advance_retries_transient_failure() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        // Setup the mock ledger, which fails to store the block on the first attempt only.
+        let num_attempts = Arc::new(AtomicUsize::new(0));
+        let num_attempts_ = num_attempts.clone();
+        let mut ledger = MockLedger::default();
+        let block_ = block.clone();
+        ledger.expect_prepare_advance_to_next_quorum_block().times(1).returning(move |_, _| Ok(block_.clone()));
+        ledger.expect_check_next_block().times(1).returning(|_| Ok(()));
+        ledger.expect_advance_to_next_block().returning(move |_| match num_attempts_.fetch_add(1, Ordering::SeqCst) {
+            0 => Err(anyhow!("Storage is temporarily unavailable")),
+            _ => Ok(()),
+        });
+        // Note: The ledger is still at the parent of the block after the failure.
+        let previous_hash = block.previous_hash();
+        ledger.expect_latest_block_hash().returning(move || previous_hash);
+        ledger.expect_latest_block().returning(move || block.clone());
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the block is advanced on the retry, without preparing it again.
+        let (callback_sender, callback_receiver) = oneshot::channel();
+        consensus.process_bft_subdag(sample_subdag(rng), Default::default(), callback_sender).await;
+        assert!(callback_receiver.await.unwrap().is_ok());
+        assert_eq!(num_attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_advance_does_not_retry_invalid_block() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        // Setup the mock ledger, which rejects the block.
+        let mut ledger = MockLedger::default();
+        ledger.expect_prepare_advance_to_next_quorum_block().times(1).returning(move |_, _| Ok(block.clone()));
+        ledger.expect_check_next_block().times(1).returning(|_| Err(anyhow!("Invalid block")));
+        ledger.expect_advance_to_next_block().never();
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the failure is reported without a retry.
+        let (callback_sender, callback_receiver) = oneshot::channel();
+        consensus.process_bft_subdag(sample_subdag(rng), Default::default(), callback_sender).await;
+        assert!(callback_receiver.await.unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_advance_does_not_retry_superseded_block() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        // Setup the mock ledger, which fails to store the block, as it has since advanced past its parent.
+        let mut ledger = MockLedger::default();
+        ledger.expect_prepare_advance_to_next_quorum_block().times(1).returning(move |_, _| Ok(block.clone()));
+        ledger.expect_check_next_block().times(1).returning(|_| Ok(()));
+        ledger.expect_advance_to_next_block().times(1).returning(|_| Err(anyhow!("The block height is stale")));
+        let latest_hash = rng.gen();
+        ledger.expect_latest_block_hash().returning(move || latest_hash);
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the failure is reported without a retry.
+        let (callback_sender, callback_receiver) = oneshot::channel();
+        consensus.process_bft_subdag(sample_subdag(rng), Default::default(), callback_sender).await;
+        assert!(callback_receiver.await.unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_expire_transactions() {
+        let rng = &mut TestRng::default();
+        let consensus = sample_consensus(MockLedger::default(), rng);
+        let mut events = consensus.subscribe_transaction_events();
+
+        // Queue a transaction, and let it exceed the TTL.
+        let ttl = Duration::from_millis(10);
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        let transaction_id = transaction.id();
+        consensus.transactions_queue.lock().insert(transaction_id, transaction).unwrap();
+        tokio::time::sleep(ttl * 2).await;
+        // Queue a fresh transaction.
+        let transaction = sample_execution_transaction_with_fee(true, rng);
+        consensus.transactions_queue.lock().insert(transaction.id(), transaction).unwrap();
+
+        // Ensure only the old transaction is expired.
+        assert_eq!(consensus.expire_transactions(ttl), 1);
+        assert_eq!(consensus.memory_pool_snapshot().num_queued_transactions(), 1);
+        assert_eq!(events.try_recv().unwrap(), TransactionEvent::Dropped(transaction_id, DropReason::Expired));
+    }
+
+    #[tokio::test]
+    async fn test_add_duplicate_solution() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        // Setup the mock ledger.
+        let mut ledger = MockLedger::default();
+        ledger.expect_contains_transmission().returning(|_| Ok(false));
+        ledger.expect_latest_block().returning(move || block.clone());
+        ledger.expect_get_proof_target().returning(|_| Ok(1));
+        let consensus = sample_consensus(ledger, rng);
+        // Set the primary sender.
+        let (primary_sender, _primary_receiver) = init_primary_channels();
+        consensus.primary_sender.set(primary_sender).unwrap();
+
+        // Sample a solution.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let solution = Solution::new(rng.gen(), Address::try_from(private_key).unwrap(), rng.gen()).unwrap();
+
+        // Ensure the first submission is accepted, and the second is rejected.
+        assert!(consensus.add_unconfirmed_solution(solution).await.is_ok());
+        assert!(matches!(
+            consensus.add_unconfirmed_solution(solution).await,
+            Err(ConsensusError::SolutionAlreadyExists(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        // Setup the mock ledger.
+        let mut ledger = MockLedger::default();
+        ledger.expect_latest_block().returning(move || block.clone());
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure an instance that is not running is unhealthy.
+        let report = consensus.health_check();
+        assert!(!report.bft_running);
+        assert!(!report.handles_alive);
+        assert_eq!(report.overall, HealthStatus::Unhealthy);
+
+        // Start the consensus handlers, as `run` does.
+        let (primary_sender, _primary_receiver) = init_primary_channels();
+        consensus.primary_sender.set(primary_sender).unwrap();
+        let (_consensus_sender, consensus_receiver) = init_consensus_channels();
+        consensus.start_handlers(consensus_receiver);
+
+        // Ensure the running instance reports as running.
+        let report = consensus.health_check();
+        assert!(report.bft_running);
+        assert!(report.primary_sender_initialized);
+        assert!(report.handles_alive);
+        assert_eq!(report.mempool_size, 0);
+        assert_ne!(report.overall, HealthStatus::Unhealthy);
+
+        // Ensure the instance is unhealthy after shutting down.
+        consensus.shut_down().await;
+        assert_eq!(consensus.health_check().overall, HealthStatus::Unhealthy);
+    }
+
+    #[tokio::test]
+    async fn test_check_transaction_min_fee() {
+        let rng = &mut TestRng::default();
+        // Setup the mock ledger.
+        let mut ledger = MockLedger::default();
+        ledger.expect_contains_transmission().returning(|_| Ok(false));
+        ledger.expect_check_transaction_basic().returning(|_, _| Ok(()));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Sample a transaction, and retrieve its fee.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        let fee = *transaction.fee_amount().unwrap();
+
+        // Ensure a fee at exactly the floor is accepted.
+        consensus.set_min_fee(fee);
+        assert!(consensus.check_transaction(&transaction).await.is_ok());
+        // Ensure a fee just below the floor is rejected.
+        consensus.set_min_fee(fee + 1);
+        assert!(matches!(
+            consensus.check_transaction(&transaction).await,
+            Err(ConsensusError::FeeTooLow { required, actual }) if required == fee + 1 && actual == fee
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_block() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        let (hash, block_) = (block.hash(), block.clone());
+        // Setup the mock ledger, which only contains the genesis block.
+        let mut ledger = MockLedger::default();
+        ledger.expect_get_block().returning(move |height| match height {
+            0 => Ok(block_.clone()),
+            _ => Err(anyhow!("Missing block {height}")),
+        });
+        ledger.expect_get_block_height().returning(move |block_hash| match *block_hash == hash {
+            true => Ok(0),
+            false => Err(anyhow!("Missing block hash")),
+        });
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the genesis block is found by height and by hash.
+        assert_eq!(consensus.get_block(0).unwrap(), block);
+        assert_eq!(consensus.get_block_by_hash(&hash).unwrap(), block);
+        // Ensure a missing block is reported as not found.
+        assert!(matches!(consensus.get_block(1), Err(ConsensusError::BlockNotFound(_))));
+        assert!(matches!(consensus.get_block_by_hash(&rng.gen()), Err(ConsensusError::BlockNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_persist_memory_pool() {
+        let rng = &mut TestRng::default();
+        let path = std::env::temp_dir().join(format!("memory_pool-{}.snapshot", rng.gen::<u64>()));
+
+        // Persist a memory pool with one queued transaction.
+        let consensus = sample_consensus(MockLedger::default(), rng);
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        consensus.transactions_queue.lock().insert(transaction.id(), transaction).unwrap();
+        consensus.persist_to(&path).unwrap();
+        // Ensure the snapshot is renamed into place.
+        assert!(path.exists());
+        assert!(!path.with_extension("tmp").exists());
+
+        // Setup a mock ledger, which has not confirmed the transaction.
+        let mut ledger = MockLedger::default();
+        ledger.expect_contains_transmission().returning(|_| Ok(false));
+        let consensus = sample_consensus(ledger, rng);
+        accept_unconfirmed_transactions(&consensus);
+
+        // Ensure the transaction is reloaded, and the snapshot is removed.
+        assert_eq!(consensus.load_from(&path).await.unwrap(), 1);
+        assert!(!path.exists());
+        // Ensure a missing snapshot loads nothing.
+        assert_eq!(consensus.load_from(&path).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_load_keeps_transiently_skipped_entries() {
+        let rng = &mut TestRng::default();
+        let path = std::env::temp_dir().join(format!("memory_pool-{}.snapshot", rng.gen::<u64>()));
+
+        // Persist a memory pool with one queued transaction.
+        let consensus = sample_consensus(MockLedger::default(), rng);
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        consensus.transactions_queue.lock().insert(transaction.id(), transaction).unwrap();
+        consensus.persist_to(&path).unwrap();
+
+        // Setup a mock ledger, which has not confirmed the transaction.
+        let mut ledger = MockLedger::default();
+        ledger.expect_contains_transmission().returning(|_| Ok(false));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the transaction is kept in the snapshot, while consensus is not running.
+        assert_eq!(consensus.load_from(&path).await.unwrap(), 0);
+        assert!(path.exists());
+        // Ensure the transaction is reloaded once consensus is running, and the snapshot is removed.
+        accept_unconfirmed_transactions(&consensus);
+        assert_eq!(consensus.load_from(&path).await.unwrap(), 1);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_catchup_from_checkpoint() {
+        let rng = &mut TestRng::default();
+        let hash = sample_genesis_block(rng).hash();
+        // Setup the mock ledger, which only contains the genesis block.
+        let mut ledger = MockLedger::default();
+        ledger.expect_get_block_hash().returning(move |height| match height {
+            0 => Ok(hash),
+            _ => Err(anyhow!("Missing block {height}")),
+        });
+        ledger.expect_latest_block_height().returning(|| 0);
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure an unknown checkpoint is rejected.
+        let result = consensus.catchup_from_checkpoint(0, hash, vec![]).await;
+        assert!(matches!(result, Err(CatchupError::UnknownCheckpoint { height: 0 })));
+        // Ensure a checkpoint which does not match the known checkpoint is rejected.
+        consensus.add_checkpoint(0, hash);
+        let result = consensus.catchup_from_checkpoint(0, rng.gen(), vec![]).await;
+        assert!(matches!(result, Err(CatchupError::CheckpointMismatch { height: 0, .. })));
+        // Ensure a checkpoint which is not in the ledger is rejected.
+        consensus.add_checkpoint(1, hash);
+        let result = consensus.catchup_from_checkpoint(1, hash, vec![]).await;
+        assert!(matches!(result, Err(CatchupError::CheckpointMismatch { height: 1, .. })));
+        // Ensure a matching checkpoint is accepted.
+        assert_eq!(consensus.catchup_from_checkpoint(0, hash, vec![]).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_add_unconfirmed_transactions() {
+        let rng = &mut TestRng::default();
+        let valid = sample_execution_transaction_with_fee(false, rng);
+        let invalid = sample_execution_transaction_with_fee(true, rng);
+        let invalid_id = invalid.id();
+        // Setup the mock ledger.
+        let mut ledger = MockLedger::default();
+        ledger.expect_contains_transmission().returning(|_| Ok(false));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the batch is rejected before consensus is running.
+        let results = consensus.add_unconfirmed_transactions(vec![valid.clone(), invalid.clone()]).await;
+        assert!(results.iter().all(|result| matches!(result, Err(ConsensusError::NotRunning))));
+
+        // Ensure each transaction in a mixed batch has its own result, in order.
+        // Note: The primary rejects the invalid transaction, as its worker fails to verify it.
+        verify_unconfirmed_transactions(&consensus, move |transaction_id| {
+            ensure!(transaction_id != invalid_id, "Invalid proof");
+            Ok(())
+        });
+        let results = consensus.add_unconfirmed_transactions(vec![invalid.clone(), valid, invalid]).await;
+        assert_eq!(results.len(), 3);
+        assert!(matches!(results[0], Err(ConsensusError::TransactionInvalid(_))));
+        assert!(results[1].is_ok());
+        assert!(matches!(results[2], Err(ConsensusError::TransactionInvalid(_))));
+    }
+
+    #[test]
+    fn test_is_leader_for_round() {
+        let rng = &mut TestRng::default();
+        let mut ledger = MockLedger::default();
+        let committee = Arc::new(OnceCell::new());
+        let committee_ = committee.clone();
+        ledger.expect_get_committee_lookback_for_round().returning(move |_| Ok(committee_.get().cloned().unwrap()));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Sample a 4-member committee, which includes this node.
+        let address = consensus.bft.primary().gateway().account().address();
+        let mut members = (0..3).map(|_| Address::try_from(PrivateKey::new(rng).unwrap()).unwrap()).collect_vec();
+        members.push(address);
+        let sampled_committee =
+            snarkvm::ledger::committee::test_helpers::sample_committee_for_round_and_members(1, members, rng);
+        committee.set(sampled_committee.clone()).unwrap();
+
+        // Ensure the leader rotates across the committee, and matches the committee's leader election.
+        let mut leaders = HashSet::new();
+        for round in (2..=100).step_by(2) {
+            let leader = sampled_committee.get_leader(round).unwrap();
+            assert_eq!(consensus.get_round_leader(round).unwrap(), leader);
+            assert_eq!(consensus.is_leader_for_round(round), leader == address);
+            leaders.insert(leader);
+        }
+        assert!(leaders.len() > 1);
+        assert!(leaders.contains(&address));
+        // Ensure there is no leader for odd rounds.
+        assert!((1..100).step_by(2).all(|round| !consensus.is_leader_for_round(round)));
+        assert!(matches!(consensus.get_round_leader(1), Err(ConsensusError::NoLeaderForRound(1))));
+
+        // Ensure the genesis round, and rounds beyond the committee lookback range, are out of range.
+        let max_round = consensus.bft.storage().current_round() + Committee::<CurrentNetwork>::COMMITTEE_LOOKBACK_RANGE;
+        assert!(matches!(consensus.get_round_leader(0), Err(ConsensusError::RoundOutOfRange(0))));
+        assert!(consensus.get_round_leader(max_round - max_round % 2).is_ok());
+        assert!(matches!(consensus.get_round_leader(max_round + 2), Err(ConsensusError::RoundOutOfRange(_))));
+        assert!(!consensus.is_leader_for_round(max_round + 2));
+    }
+
+    #[test]
+    fn test_get_committee() {
+        let rng = &mut TestRng::default();
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        let committee_ = committee.clone();
+        // Setup the mock ledger, which only knows the committees up to round 1000.
+        let mut ledger = MockLedger::default();
+        ledger.expect_get_committee_for_round().returning(move |round| match round <= 1000 {
+            true => Ok(committee_.clone()),
+            false => Err(anyhow!("Missing committee for round {round}")),
+        });
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the committee for the current round is found.
+        assert_eq!(consensus.get_committee().unwrap(), committee);
+        assert_eq!(consensus.get_committee_for_round(1000).unwrap(), committee);
+        // Ensure an unknown round is reported as not found.
+        assert!(matches!(consensus.get_committee_for_round(1001), Err(ConsensusError::CommitteeNotFound(1001))));
+    }
+
+    #[test]
+    fn test_committee() {
+        let rng = &mut TestRng::default();
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        let committee_ = committee.clone();
+        // Note: The storage starts at the starting round of the current committee, which is at least 1.
+        let storage_round = committee.starting_round().max(1);
+        // Setup the mock ledger, which only knows the committee lookback of the storage round.
+        let mut ledger = MockLedger::default();
+        ledger.expect_current_committee().returning(move || Ok(committee_.clone()));
+        let committee_ = committee.clone();
+        ledger.expect_get_committee_lookback_for_round().returning(move |round| match round == storage_round {
+            true => Ok(committee_.clone()),
+            false => Err(anyhow!("Missing committee lookback for round {round}")),
+        });
+        let consensus = sample_consensus_with(Arc::new(ledger), ConsensusConfig::default(), rng);
+
+        // Ensure the committee lookback is returned along with the current round of the storage.
+        assert_eq!(consensus.bft().storage().current_round(), storage_round);
+        assert_eq!(consensus.committee().unwrap(), (committee, storage_round));
+    }
+
+    #[test]
+    fn test_inclusion_proof() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        let (hash, block_) = (block.hash(), block.clone());
+        let transaction_id = *block.transaction_ids().next().unwrap();
+        // Setup the mock ledger, which only contains the genesis block.
+        let mut ledger = MockLedger::default();
+        ledger.expect_find_block_hash().returning(move |id| Ok((*id == transaction_id).then_some(hash)));
+        ledger.expect_get_block_height().returning(|_| Ok(0));
+        ledger.expect_get_block().returning(move |_| Ok(block_.clone()));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the proof for the included transaction verifies against the block header.
+        let proof = consensus.inclusion_proof(TransmissionID::Transaction(transaction_id)).unwrap();
+        assert_eq!(proof.block_height(), 0);
+        assert!(proof.verify(block.header()));
+        // Ensure the proof does not verify for another transaction.
+        let forged = InclusionProof::new(0, rng.gen(), proof.transactions_path().clone());
+        assert!(!forged.verify(block.header()));
+        // Ensure there is no proof for a transaction that is not included.
+        assert!(consensus.inclusion_proof(TransmissionID::Transaction(rng.gen())).is_none());
+    }
+
+    #[test]
+    fn test_estimate_next_block_time() {
+        let rng = &mut TestRng::default();
+        let consensus = sample_consensus(MockLedger::default(), rng);
+
+        // Ensure the default block time is used, until there are 2 blocks.
+        let now = SystemTime::now();
+        assert!(consensus.estimate_next_block_time() >= now + DEFAULT_BLOCK_TIME);
+        consensus.record_block_timestamp(0);
+        assert!(consensus.estimate_next_block_time() >= now + DEFAULT_BLOCK_TIME);
+
+        // Ensure the estimate adds the average block time to the latest block.
+        consensus.record_block_timestamp(10);
+        consensus.record_block_timestamp(30);
+        assert_eq!(consensus.estimate_next_block_time(), UNIX_EPOCH + Duration::from_secs(45));
+
+        // Ensure only the blocks in the window are retained.
+        (4..20).for_each(|i| consensus.record_block_timestamp(i * 10));
+        assert_eq!(consensus.block_timestamps.lock().len(), consensus.config.block_time_window());
+        assert_eq!(consensus.estimate_next_block_time(), UNIX_EPOCH + Duration::from_secs(200));
+    }
+
+    #[test]
+    fn test_drain_memory_pool() {
+        let rng = &mut TestRng::default();
+        let consensus = sample_consensus(MockLedger::default(), rng);
+
+        // Queue a transaction and a solution.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        consensus.transactions_queue.lock().insert(transaction.id(), transaction.clone()).unwrap();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let solution = Solution::new(rng.gen(), Address::try_from(private_key).unwrap(), rng.gen()).unwrap();
+        consensus.solutions_queue.lock().put(solution.id(), solution);
+
+        // Ensure the drain returns the queued entries, and leaves the memory pool empty.
+        let mut events = consensus.subscribe_transaction_events();
+        let (transactions, solutions) = consensus.drain_memory_pool();
+        assert_eq!(transactions, vec![transaction.clone()]);
+        assert_eq!(solutions, vec![solution]);
+        assert_eq!(events.try_recv().unwrap(), TransactionEvent::Dropped(transaction.id(), DropReason::Removed));
+        let snapshot = consensus.memory_pool_snapshot();
+        assert_eq!(snapshot.num_queued_transactions() + snapshot.num_queued_solutions(), 0);
+        assert_eq!(consensus.num_unconfirmed_transmissions(), 0);
+        // Ensure a second drain returns nothing.
+        assert_eq!(consensus.drain_memory_pool(), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_remove_unconfirmed_transaction() {
+        let rng = &mut TestRng::default();
+        let consensus = sample_consensus(MockLedger::default(), rng);
+
+        // Queue a transaction, which was seen.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        consensus.transactions_queue.lock().insert(transaction.id(), transaction.clone()).unwrap();
+        consensus.seen_transactions.lock().put(transaction.id(), ());
+
+        // Ensure the transaction is removed and forgotten, so that a resubmission is not skipped.
+        let mut events = consensus.subscribe_transaction_events();
+        assert!(consensus.remove_unconfirmed_transaction(transaction.id()));
+        assert_eq!(events.try_recv().unwrap(), TransactionEvent::Dropped(transaction.id(), DropReason::Removed));
+        assert!(!consensus.seen_transactions.lock().contains(&transaction.id()));
+        // Ensure a second removal finds nothing.
+        assert!(!consensus.remove_unconfirmed_transaction(transaction.id()));
+    }
+
+    #[test]
+    fn test_clear_transactions_by_sender() {
+        let rng = &mut TestRng::default();
+        let consensus = sample_consensus(MockLedger::default(), rng);
+
+        // Queue a transaction with a public fee, and one with a private fee.
+        let public = sample_execution_transaction_with_fee(false, rng);
+        let private = sample_execution_transaction_with_fee(true, rng);
+        consensus.transactions_queue.lock().insert(public.id(), public.clone()).unwrap();
+        consensus.transactions_queue.lock().insert(private.id(), private.clone()).unwrap();
+        let payer = public.fee_transition().and_then(|fee| fee.payer()).unwrap();
+
+        // Ensure only the transaction whose fee is paid publicly by the address is removed.
+        let mut events = consensus.subscribe_transaction_events();
+        assert_eq!(consensus.clear_transactions_by_sender(&payer), 1);
+        assert_eq!(events.try_recv().unwrap(), TransactionEvent::Dropped(public.id(), DropReason::Removed));
+        assert!(consensus.transactions_queue.lock().executions.contains(&private.id()));
+        // Ensure a second clear finds nothing.
+        assert_eq!(consensus.clear_transactions_by_sender(&payer), 0);
+    }
+
+    #[test]
+    fn test_fork_at() {
+        let rng = &mut TestRng::default();
+        // Initialize the consensus with a ledger at height 5.
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        let ledger = Arc::new(MockLedgerService::new_at_height(committee, 5));
+        let consensus = sample_consensus_with(ledger, ConsensusConfig::default(), rng);
+
+        // Ensure the fork shares the ledger up to the given height.
+        let fork = consensus.fork_at(3).unwrap();
+        assert_eq!(fork.ledger.latest_block_height(), 3);
+        assert_eq!(fork.ledger.get_block_hash(3).unwrap(), consensus.ledger.get_block_hash(3).unwrap());
+        assert!(!fork.ledger.contains_block_height(4));
+        // Ensure the original ledger is unaffected.
+        assert_eq!(consensus.ledger.latest_block_height(), 5);
+        // Ensure forking above the latest height fails.
+        assert!(consensus.fork_at(6).is_err());
+
+        // Ensure forking fails if the ledger does not support snapshots.
+        let mut ledger = MockLedger::default();
+        ledger.expect_latest_block_height().returning(|| 5);
+        ledger.expect_fork_at().returning(|height| bail!("Cannot fork the ledger at block {height}"));
+        let consensus = sample_consensus(ledger, rng);
+        assert!(consensus.fork_at(3).is_err());
+    }
+
+    #[test]
+    fn test_solution_requirements() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        let (proof_target, coinbase_target) = (block.proof_target(), block.coinbase_target());
+
+        let mut ledger = MockLedger::default();
+        ledger.expect_latest_block().returning(move || block.clone());
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the reported targets match the latest block.
+        let requirements = consensus.solution_requirements().unwrap();
+        assert_eq!(requirements.latest_proof_target, proof_target);
+        assert_eq!(requirements.latest_coinbase_target, coinbase_target);
+        assert_eq!(requirements.epoch_number, 0);
+        assert!(!requirements.coinbase_target_met);
+    }
+
+    #[test]
+    fn test_get_unconfirmed_solution() {
+        let rng = &mut TestRng::default();
+        let consensus = sample_consensus(MockLedger::default(), rng);
+
+        // Queue a solution.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let solution = Solution::new(rng.gen(), address, rng.gen()).unwrap();
+        consensus.solutions_queue.lock().put(solution.id(), solution);
+
+        // Ensure the solution is found by its ID, with matching fields.
+        let found = consensus.get_unconfirmed_solution(&solution.id()).unwrap();
+        assert_eq!(found.id(), solution.id());
+        assert_eq!(found.address(), address);
+        assert_eq!(found.counter(), solution.counter());
+        // Ensure an unknown solution is not found.
+        let other = Solution::new(rng.gen(), address, rng.gen()).unwrap();
+        assert!(consensus.get_unconfirmed_solution(&other.id()).is_none());
+    }
+
+    #[test]
+    fn test_pending_fees_total() {
+        let rng = &mut TestRng::default();
+        let consensus = sample_consensus(MockLedger::default(), rng);
+        assert_eq!(consensus.pending_fees_total().unwrap(), 0);
+
+        // Queue a transaction.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        let fee = *transaction.fee_amount().unwrap();
+        consensus.transactions_queue.lock().insert(transaction.id(), transaction.clone()).unwrap();
+
+        // Ensure the cached total matches the fee, and the recalculated total.
+        assert_eq!(consensus.pending_fees_total().unwrap(), fee);
+        assert_eq!(consensus.pending_fees_total().unwrap(), consensus.recalculate_pending_fees().unwrap());
+
+        // Ensure the total is updated on removal.
+        consensus.transactions_queue.lock().remove(&transaction.id());
+        assert_eq!(consensus.pending_fees_total().unwrap(), 0);
+        assert_eq!(consensus.recalculate_pending_fees().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_advance_to_next_block_with_timestamp_rejects_invalid_height() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        // Setup the mock ledger, which is already past the genesis block.
+        let mut ledger = MockLedger::default();
+        ledger.expect_latest_block_height().returning(|| 1);
+        ledger.expect_check_next_block().never();
+        ledger.expect_advance_to_next_block().never();
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the block is rejected, and the timestamp is not recorded.
+        let result = consensus.advance_to_next_block_with_timestamp(&block, block.timestamp() + 100);
+        assert!(matches!(result, Err(ConsensusError::BlockInvalid(BlockValidationError::InvalidHeight { .. }))));
+        assert!(consensus.block_timestamps.lock().is_empty());
+    }
+
+    #[test]
+    fn test_advance_to_next_block_with_timestamp() {
+        let rng = &mut TestRng::default();
+        // Prepare the next blocks on a ledger, which starts from the genesis block.
+        let genesis = sample_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        let source =
+            Ledger::<CurrentNetwork, ConsensusMemory<_>>::load(genesis.clone(), StorageMode::Development(0)).unwrap();
+        let block_1 = source.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        source.advance_to_next_block(&block_1).unwrap();
+        let block_2 = source.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+
+        // Setup the mock ledger, which advances as the blocks are stored.
+        let latest = Arc::new(Mutex::new(genesis));
+        let mut ledger = MockLedger::default();
+        let latest_ = latest.clone();
+        ledger.expect_latest_block_height().returning(move || latest_.lock().height());
+        let latest_ = latest.clone();
+        ledger.expect_latest_block_hash().returning(move || latest_.lock().hash());
+        let latest_ = latest.clone();
+        ledger.expect_latest_block().returning(move || latest_.lock().clone());
+        ledger.expect_check_next_block().returning(|_| Ok(()));
+        let latest_ = latest.clone();
+        ledger.expect_advance_to_next_block().returning(move |block| {
+            *latest_.lock() = block.clone();
+            Ok(())
+        });
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the block is advanced, and the overridden timestamp is recorded in place of the block timestamp.
+        let timestamp_1 = block_1.timestamp() + 3600;
+        consensus.advance_to_next_block_with_timestamp(&block_1, timestamp_1).unwrap();
+        assert_eq!(consensus.latest_block_hash(), block_1.hash());
+        let expected = UNIX_EPOCH + Duration::from_secs(timestamp_1 as u64);
+        assert_eq!(consensus.block_timestamps.lock().back(), Some(&expected));
+
+        // Ensure the next block is validated against the overridden timestamp, rather than the block timestamp.
+        let result = consensus.advance_to_next_block_with_timestamp(&block_2, block_2.timestamp());
+        assert!(matches!(result, Err(ConsensusError::BlockInvalid(BlockValidationError::InvalidTimestamp { .. }))));
+        assert_eq!(consensus.latest_block_hash(), block_1.hash());
+        // Ensure the block time is estimated from the overridden timestamps.
+        let timestamp_2 = timestamp_1 + 3600;
+        consensus.advance_to_next_block_with_timestamp(&block_2, timestamp_2).unwrap();
+        assert_eq!(consensus.estimate_next_block_time(), UNIX_EPOCH + Duration::from_secs((timestamp_2 + 3600) as u64));
+    }
+
+    #[test]
+    fn test_candidate_solutions_stop_at_coinbase_target() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        let coinbase_target = block.header().coinbase_target();
+        // Setup the mock ledger, where each solution contributes just over half of the coinbase target.
+        let mut ledger = MockLedger::default();
+        ledger.expect_latest_block().returning(move || block.clone());
+        ledger.expect_get_proof_target().returning(move |_| Ok(coinbase_target / 2 + 1));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Queue the solutions.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let mut solutions = (0..5).map(|_| Solution::new(rng.gen(), address, rng.gen()).unwrap()).collect::<Vec<_>>();
+        for solution in &solutions {
+            consensus.solutions_queue.lock().put(solution.id(), *solution);
+        }
+        solutions.sort_unstable_by_key(|solution| *solution.id());
+
+        // Ensure the selection stops once the coinbase target is reached, in order of ID.
+        assert_eq!(consensus.candidate_solutions(0).unwrap(), solutions[..2]);
+        // Ensure the selection stops once the coinbase target plus the margin is reached.
+        assert_eq!(consensus.candidate_solutions(10).unwrap(), solutions[..3]);
+        // Ensure the selection includes every solution if the target plus the margin is never reached.
+        assert_eq!(consensus.candidate_solutions(500).unwrap(), solutions);
+    }
+
+    #[test]
+    fn test_estimate_next_block_reward() {
+        let rng = &mut TestRng::default();
+        // Prepare the next block on a ledger, which starts from the genesis block.
+        let genesis = sample_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        let source =
+            Ledger::<CurrentNetwork, ConsensusMemory<_>>::load(genesis.clone(), StorageMode::Development(0)).unwrap();
+        let next_block =
+            source.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        // Determine the reward of the next block, from its ratifications.
+        let reward = next_block
+            .ratifications()
+            .iter()
+            .map(|ratify| match ratify {
+                Ratify::BlockReward(reward) | Ratify::PuzzleReward(reward) => *reward,
+                _ => 0,
+            })
+            .sum::<u64>();
+
+        // Setup the mock ledger, which is at the genesis block.
+        let mut ledger = MockLedger::default();
+        ledger.expect_latest_block().returning(move || genesis.clone());
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the estimate for an empty memory pool matches the reward of the next block.
+        assert_eq!(consensus.estimate_next_block_reward().unwrap(), reward);
+        // Ensure a transaction in the inbound queue is not counted, as it is not yet proposed.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        consensus.transactions_queue.lock().insert(transaction.id(), transaction).unwrap();
+        assert_eq!(consensus.estimate_next_block_reward().unwrap(), reward);
+    }
+
+    #[test]
+    fn test_is_validator() {
+        let rng = &mut TestRng::default();
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        let (member, (stake, ..)) = committee.members().first().map(|(address, entry)| (*address, *entry)).unwrap();
+        let consensus =
+            sample_consensus_with(Arc::new(MockLedgerService::new(committee)), ConsensusConfig::default(), rng);
+
+        // Ensure a committee member is a validator, with its stake.
+        assert!(consensus.is_validator(&member));
+        assert_eq!(consensus.validator_stake(&member), Some(stake));
+        // Ensure an unknown address is not a validator.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(!consensus.is_validator(&address));
+        assert_eq!(consensus.validator_stake(&address), None);
+    }
+
+    #[test]
+    fn test_get_unconfirmed_transmissions_paginated() {
+        let rng = &mut TestRng::default();
+        let consensus = sample_consensus(MockLedger::default(), rng);
+
+        // Ensure an empty memory pool returns a single empty page.
+        assert_eq!(consensus.get_unconfirmed_transmissions_paginated(None, 2).unwrap(), (vec![], None));
+        // Ensure a zero limit is rejected.
+        assert!(matches!(
+            consensus.get_unconfirmed_transmissions_paginated(None, 0),
+            Err(ConsensusError::ZeroPageLimit)
+        ));
+        // Ensure an unknown cursor is reported, instead of returning an empty last page.
+        let cursor = TransmissionID::Solution(rng.gen::<u64>().into());
+        assert!(matches!(
+            consensus.get_unconfirmed_transmissions_paginated(Some(cursor), 2),
+            Err(ConsensusError::CursorNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_rate_limit() {
+        let rng = &mut TestRng::default();
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        let mut ledger = MockLedger::default();
+        ledger.expect_current_committee().returning(move || Ok(committee.clone()));
+        let config =
+            ConsensusConfig { per_address_rate: Some(RateLimit { burst: 2, per_second: 1 }), ..Default::default() };
+        let consensus = sample_consensus_with(Arc::new(ledger), config, rng);
+
+        // Ensure a burst within the limit passes, and the excess is rejected.
+        // Note: The transaction has a public fee, so its fee payer is known.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        let payer = fee_payer(&transaction);
+        for _ in 0..2 {
+            assert!(consensus.check_rate_limit(&transaction).is_ok());
+            // Ensure checking the rate limit does not take a token.
+            assert!(consensus.check_rate_limit(&transaction).is_ok());
+            consensus.charge_rate_limit(payer);
+        }
+        assert!(matches!(consensus.check_rate_limit(&transaction), Err(ConsensusError::RateLimited(_))));
+
+        // Ensure transactions are not rate limited without a configured limit.
+        let consensus = sample_consensus(MockLedger::default(), rng);
+        assert!((0..10).all(|_| consensus.check_rate_limit(&transaction).is_ok()));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_block() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        // Setup the mock ledger, which has not yet confirmed the block.
+        let latest_height = Arc::new(AtomicUsize::new(0));
+        let latest_height_ = latest_height.clone();
+        let mut ledger = MockLedger::default();
+        ledger.expect_latest_block_height().returning(move || latest_height_.load(Ordering::SeqCst) as u32);
+        let block_ = block.clone();
+        ledger.expect_get_block().returning(move |_| Ok(block_.clone()));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure waiting for an unconfirmed block times out, and releases its receiver.
+        let result = consensus.wait_for_block(1, Duration::from_millis(10)).await;
+        assert!(matches!(result, Err(ConsensusError::Timeout(_))));
+        assert_eq!(consensus.new_blocks.receiver_count(), 0);
+
+        // Ensure a notified block below the height is skipped.
+        let consensus_ = consensus.clone();
+        let handle = tokio::spawn(async move { consensus_.wait_for_block(1, Duration::from_secs(5)).await });
+        while consensus.new_blocks.receiver_count() == 0 {
+            tokio::task::yield_now().await;
+        }
+        consensus.notify_new_block(&block);
+        tokio::task::yield_now().await;
+        assert!(!handle.is_finished());
+        // Ensure the block is returned once it is confirmed.
+        latest_height.store(1, Ordering::SeqCst);
+        consensus.notify_new_block(&block);
+        assert_eq!(handle.await.unwrap().unwrap(), block);
+        assert_eq!(consensus.new_blocks.receiver_count(), 0);
+
+        // Ensure an already-confirmed block is returned immediately.
+        assert_eq!(consensus.wait_for_block(1, Duration::ZERO).await.unwrap(), block);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_subdags() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        // Setup the mock ledger, which advances to the block.
+        let mut ledger = MockLedger::default();
+        let block_ = block.clone();
+        ledger.expect_prepare_advance_to_next_quorum_block().times(1).returning(move |_, _| Ok(block_.clone()));
+        ledger.expect_check_next_block().returning(|_| Ok(()));
+        ledger.expect_advance_to_next_block().times(1).returning(|_| Ok(()));
+        ledger.expect_latest_block().returning(move || block.clone());
+        let consensus = sample_consensus(ledger, rng);
+        let mut receiver = consensus.subscribe_subdags();
+
+        // Ensure the subscriber receives the committed subdag, and the block is still advanced.
+        let subdag = sample_subdag(rng);
+        let (callback_sender, callback_receiver) = oneshot::channel();
+        consensus.process_bft_subdag(subdag.clone(), Default::default(), callback_sender).await;
+        assert!(callback_receiver.await.unwrap().is_ok());
+        let (received_subdag, received_transmissions) = receiver.try_recv().unwrap();
+        assert_eq!(received_subdag, subdag);
+        assert!(received_transmissions.is_empty());
+    }
+
+    #[test]
+    fn test_coinbase_ready_threshold() {
+        let config = ConsensusConfig { coinbase_target_margin: 10, coinbase_target_tolerance: 5, ..Default::default() };
+        let is_met = |cumulative_proof_target: u128, was_met: bool| {
+            cumulative_proof_target >= coinbase_ready_threshold(100, &config, was_met)
+        };
+        // Ensure the target is only met once the margin is cleared.
+        assert!(!is_met(100, false));
+        assert!(!is_met(109, false));
+        assert!(is_met(110, false));
+        // Ensure the target stays met within the tolerance.
+        assert!(is_met(100, true));
+        assert!(is_met(95, true));
+        assert!(!is_met(94, true));
+        // Ensure the default configuration compares against the coinbase target exactly.
+        let config = ConsensusConfig::default();
+        assert_eq!(coinbase_ready_threshold(100, &config, false), 100);
+        assert_eq!(coinbase_ready_threshold(100, &config, true), 100);
+    }
+
+    #[test]
+    fn test_is_coinbase_target_met_with_margin() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        let coinbase_target = u128::from(block.header().coinbase_target());
+        let cumulative_proof_target = block.header().cumulative_proof_target();
+        let mut ledger = MockLedger::default();
+        ledger.expect_latest_block().returning(move || block.clone());
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the existing method is equivalent to a zero margin.
+        let is_met = cumulative_proof_target >= coinbase_target;
+        assert_eq!(consensus.is_coinbase_target_met().unwrap(), is_met);
+        assert_eq!(consensus.is_coinbase_target_met_with_margin(0).unwrap(), is_met);
+        // Ensure a margin beyond the cumulative proof target is not met.
+        let margin = cumulative_proof_target.saturating_sub(coinbase_target) + 1;
+        assert!(!consensus.is_coinbase_target_met_with_margin(margin).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_transaction_confirmation() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        let consensus = sample_consensus(MockLedger::default(), rng);
+
+        // Ensure a transaction that is not in the memory pool is rejected immediately.
+        let (transaction_id, index) = (*block.transaction_ids().next().unwrap(), 0);
+        let result = consensus.wait_for_transaction_confirmation(transaction_id, Duration::from_secs(5)).await;
+        assert!(matches!(result, Err(ConsensusError::TransactionNotInPool(_))));
+
+        // Queue the transaction.
+        let transaction = block.transactions().iter().next().unwrap().transaction().clone();
+        consensus.transactions_queue.lock().insert(transaction_id, transaction).unwrap();
+
+        // Ensure waiting for an unconfirmed transaction times out, and releases its receiver.
+        let result = consensus.wait_for_transaction_confirmation(transaction_id, Duration::from_millis(10)).await;
+        assert!(matches!(result, Err(ConsensusError::Timeout(_))));
+        assert_eq!(consensus.new_blocks.receiver_count(), 0);
+
+        // Ensure the block that includes the transaction is returned, with the index of the transaction.
+        let consensus_ = consensus.clone();
+        let handle = tokio::spawn(async move {
+            consensus_.wait_for_transaction_confirmation(transaction_id, Duration::from_secs(5)).await
+        });
+        while consensus.new_blocks.receiver_count() == 0 {
+            tokio::task::yield_now().await;
+        }
+        consensus.notify_new_block(&block);
+        let (confirmed_block, confirmed_index) = handle.await.unwrap().unwrap();
+        assert_eq!(confirmed_block, block);
+        assert_eq!(confirmed_index, index);
+    }
+
+    #[tokio::test]
+    async fn test_evict_transaction() {
+        let rng = &mut TestRng::default();
+        let consensus = sample_consensus(MockLedger::default(), rng);
+        let mut events = consensus.subscribe_transaction_events();
+
+        // Queue a transaction.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        let transaction_id = transaction.id();
+        consensus.transactions_queue.lock().insert(transaction_id, transaction.clone()).unwrap();
+        consensus.seen_transactions.lock().put(transaction_id, ());
+
+        // Ensure the transaction is evicted, and the subscribers are notified.
+        assert!(consensus.evict_transaction(transaction_id));
+        assert!(consensus.get_unconfirmed_transaction(&transaction_id).is_none());
+        assert_eq!(events.try_recv().unwrap(), TransactionEvent::Dropped(transaction_id, DropReason::ManuallyEvicted));
+        // Ensure evicting the transaction again has no effect.
+        assert!(!consensus.evict_transaction(transaction_id));
+        assert!(events.try_recv().is_err());
+
+        // Ensure the transaction may not re-enter the memory pool within the cooldown.
+        let result = consensus.validate_unconfirmed_transaction(&transaction).await;
+        assert!(matches!(result, Err(ConsensusError::TransactionEvicted(_))));
+    }
+
+    #[test]
+    fn test_get_certificate() {
+        let rng = &mut TestRng::default();
+        let certificate = snarkvm::ledger::narwhal::batch_certificate::test_helpers::sample_batch_certificate(rng);
+        let certificate_id = certificate.id();
+        // Setup the mock ledger, which holds the committed certificate.
+        let mut ledger = MockLedger::default();
+        let certificate_ = certificate.clone();
+        ledger.expect_get_batch_certificate().returning(move |id| match *id == certificate_id {
+            true => Ok(certificate_.clone()),
+            false => Err(anyhow!("Certificate not found")),
+        });
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the committed certificate is found, and an unknown certificate is not.
+        assert_eq!(consensus.get_certificate(&certificate_id), Some(certificate));
+        assert_eq!(consensus.get_certificate(&Field::from_u64(1)), None);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_transaction_is_not_verified_again() {
+        let rng = &mut TestRng::default();
+        // Setup the mock ledger, which is never asked to verify the transaction.
+        let mut ledger = MockLedger::default();
+        ledger.expect_contains_transmission().returning(|_| Ok(false));
+        ledger.expect_check_transaction_basic().never();
+        let consensus = sample_consensus(ledger, rng);
+        // Spawn a primary, which rejects every transaction, as its worker fails to verify it.
+        let num_verified = Arc::new(AtomicUsize::new(0));
+        let num_verified_ = num_verified.clone();
+        verify_unconfirmed_transactions(&consensus, move |_| {
+            num_verified_.fetch_add(1, Ordering::SeqCst);
+            bail!("Invalid proof")
+        });
+
+        // Ensure the resubmitted transaction is rejected with the same reason, without being verified again.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        let first = consensus.add_unconfirmed_transactions(vec![transaction.clone()]).await.remove(0);
+        let second = consensus.add_unconfirmed_transactions(vec![transaction]).await.remove(0);
+        match (first, second) {
+            (Err(ConsensusError::TransactionInvalid(first)), Err(ConsensusError::TransactionInvalid(second))) => {
+                assert_eq!(first, second)
+            }
+            result => panic!("Expected the transaction to be invalid, found {result:?}"),
+        }
+        assert_eq!(num_verified.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_transaction_does_not_charge_rate_limit() {
+        let rng = &mut TestRng::default();
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        let mut ledger = MockLedger::default();
+        ledger.expect_current_committee().returning(move || Ok(committee.clone()));
+        ledger.expect_contains_transmission().returning(|_| Ok(false));
+        let config =
+            ConsensusConfig { per_address_rate: Some(RateLimit { burst: 1, per_second: 1 }), ..Default::default() };
+        let consensus = sample_consensus_with(Arc::new(ledger), config, rng);
+        // Spawn a primary, which rejects the first transaction only.
+        let num_received = Arc::new(AtomicUsize::new(0));
+        let num_received_ = num_received.clone();
+        verify_unconfirmed_transactions(&consensus, move |_| match num_received_.fetch_add(1, Ordering::SeqCst) {
+            0 => Err(anyhow!("Invalid proof")),
+            _ => Ok(()),
+        });
+
+        // Ensure the rejected transaction does not take the token of its fee payer.
+        let transaction = sample_execution_transaction_with_fee(false, rng);
+        let results = consensus.add_unconfirmed_transactions(vec![transaction.clone()]).await;
+        assert!(matches!(results[0], Err(ConsensusError::TransactionInvalid(_))));
+        // Ensure the accepted transaction takes the token.
+        consensus.seen_transactions.lock().clear();
+        consensus.invalid_transactions.lock().clear();
+        assert!(consensus.add_unconfirmed_transactions(vec![transaction.clone()]).await[0].is_ok());
+        consensus.seen_transactions.lock().clear();
+        let results = consensus.add_unconfirmed_transactions(vec![transaction]).await;
+        assert!(matches!(results[0], Err(ConsensusError::RateLimited(_))));
+        assert_eq!(num_received.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_coinbase_target_counts_queued_solutions() {
+        let rng = &mut TestRng::default();
+        let block = sample_genesis_block(rng);
+        let coinbase_target = block.header().coinbase_target();
+        // Setup the mock ledger, where each solution contributes just over half of the coinbase target.
+        let mut ledger = MockLedger::default();
+        let latest_block = block.clone();
+        ledger.expect_latest_block().returning(move || latest_block.clone());
+        ledger.expect_get_proof_target().returning(move |_| Ok(coinbase_target / 2 + 1));
+        let consensus = sample_consensus(ledger, rng);
+
+        // Ensure the solutions in the inbound queue count towards the coinbase target, as for the candidates.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        for i in 0..2 {
+            assert!(!consensus.is_coinbase_target_met().unwrap(), "Met with {i} solution(s)");
+            let solution = Solution::new(rng.gen(), address, rng.gen()).unwrap();
+            consensus.solutions_queue.lock().put(solution.id(), solution);
+            consensus.reset_proof_target_cache(&block);
+        }
+        assert!(consensus.is_coinbase_target_met().unwrap());
+        assert_eq!(consensus.candidate_solutions(0).unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_full_memory_pool_skips_queued_transaction() {
+        let rng = &mut TestRng::default();
+        let committee = snarkvm::ledger::committee::test_helpers::sample_committee(rng);
+        let mut ledger = MockLedger::default();
+        ledger.expect_current_committee().returning(move || Ok(committee.clone()));
+        ledger.expect_contains_transmission().returning(|_| Ok(false));
+        let memory_pool = MemoryPoolConfig { max_transactions: 1, ..Default::default() };
+        let config = ConsensusConfig { memory_pool, ..Default::default() };
+        let consensus = sample_consensus_with(Arc::new(ledger), config, rng);
+
+        // Fill the memory pool with a transaction that was seen.
+        let queued = sample_execution_transaction_with_fee(false, rng);
+        consensus.seen_transactions.lock().put(queued.id(), ());
+        consensus.transactions_queue.lock().insert(queued.id(), queued.clone()).unwrap();
+
+        // Ensure resubmitting the queued transaction is skipped, rather than rejected.
+        assert!(!consensus.validate_unconfirmed_transaction(&queued).await.unwrap());
+        // Ensure a new transaction is rejected, without being marked as seen.
+        let transaction = sample_execution_transaction_with_fee(true, rng);
+        assert!(matches!(
+            consensus.validate_unconfirmed_transaction(&transaction).await,
+            Err(ConsensusError::MemoryPoolFull)
+        ));
+        assert!(!consensus.seen_transactions.lock().contains(&transaction.id()));
     }
 }
@@ -0,0 +1,665 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ConsensusError;
+use snarkos_node_bft::helpers::fmt_id;
+use snarkvm::{
+    ledger::{block::Transaction, puzzle::SolutionID},
+    prelude::{ensure, Address, Field, Network, Result, ToBytes},
+};
+
+use lru::LruCache;
+use std::{
+    collections::{HashMap, HashSet},
+    num::NonZeroUsize,
+    time::{Duration, Instant},
+};
+
+/// The capacity of the queue reserved for deployments.
+/// Note: This is an inbound queue capacity, not a Narwhal-enforced capacity.
+const CAPACITY_FOR_DEPLOYMENTS: usize = 1 << 10;
+/// The capacity of the queue reserved for executions.
+/// Note: This is an inbound queue capacity, not a Narwhal-enforced capacity.
+const CAPACITY_FOR_EXECUTIONS: usize = 1 << 10;
+/// The default capacity of the queue reserved for solutions.
+/// Note: This is an inbound queue capacity, not a Narwhal-enforced capacity.
+const DEFAULT_MAX_SOLUTIONS: usize = 1 << 10;
+/// The default maximum number of unconfirmed transactions, across the inbound queue and the memory pool.
+const DEFAULT_MAX_TRANSACTIONS: usize = 50_000;
+/// The default maximum size of a transaction.
+const DEFAULT_MAX_TRANSACTION_SIZE_IN_BYTES: usize = 128_000; // 128 kB
+/// The default capacity of the new transactions channel.
+const DEFAULT_BROADCAST_CAPACITY: usize = 1 << 10;
+/// The default minimum increase in fee-per-byte, in percent, for a transaction to replace a conflicting one.
+pub const MIN_FEE_BUMP_PERCENT: u64 = 10;
+
+/// The policy for selecting which transaction to evict when the inbound queue is full.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Evicts the least-recently-inserted transaction.
+    #[default]
+    OldestFirst,
+    /// Evicts the transaction with the lowest fee, breaking ties by age.
+    LowestFeeFirst,
+}
+
+/// The configuration of the memory pool.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MemoryPoolConfig {
+    /// The maximum number of unconfirmed transactions, across the inbound queue and the memory pool.
+    pub max_transactions: usize,
+    /// The maximum number of solutions in the inbound queue.
+    pub max_solutions: usize,
+    /// The maximum size of a transaction, in bytes.
+    pub max_transaction_size_bytes: usize,
+    /// The policy for selecting which transaction to evict when the inbound queue is full.
+    pub eviction_policy: EvictionPolicy,
+    /// The number of new transactions buffered for each subscriber before the slowest one starts lagging.
+    pub broadcast_capacity: usize,
+    /// The minimum increase in fee-per-byte, in percent, for a transaction to replace a conflicting one.
+    pub min_fee_bump_percent: u64,
+}
+
+impl Default for MemoryPoolConfig {
+    /// Initializes a new memory pool configuration with the default limits.
+    fn default() -> Self {
+        Self {
+            max_transactions: DEFAULT_MAX_TRANSACTIONS,
+            max_solutions: DEFAULT_MAX_SOLUTIONS,
+            max_transaction_size_bytes: DEFAULT_MAX_TRANSACTION_SIZE_IN_BYTES,
+            eviction_policy: EvictionPolicy::default(),
+            broadcast_capacity: DEFAULT_BROADCAST_CAPACITY,
+            min_fee_bump_percent: MIN_FEE_BUMP_PERCENT,
+        }
+    }
+}
+
+impl MemoryPoolConfig {
+    /// Ensures the configured limits are non-zero.
+    pub fn ensure_is_valid(&self) -> Result<()> {
+        ensure!(self.max_transactions > 0, "The memory pool must allow at least one transaction");
+        ensure!(self.max_solutions > 0, "The memory pool must allow at least one solution");
+        ensure!(self.max_transaction_size_bytes > 0, "The memory pool must allow a non-zero transaction size");
+        ensure!(self.broadcast_capacity > 0, "The new transactions channel must have a non-zero capacity");
+        // Note: A non-zero fee bump ensures a replacement always pays strictly more than the transaction it replaces.
+        ensure!(self.min_fee_bump_percent > 0, "The minimum fee bump for a replacement must be non-zero");
+        Ok(())
+    }
+}
+
+/// The outcome of an attempt to replace a queued transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplacementOutcome<N: Network> {
+    /// The conflicting transaction was replaced, and is returned.
+    Replaced(Transaction<N>),
+    /// The conflicting transaction was kept, as the fee bump was insufficient.
+    NotReplaced,
+    /// There was no conflicting transaction, so the transaction was added as a new one.
+    New,
+}
+
+/// A transaction in the inbound queue, along with the time it was inserted.
+pub(crate) struct QueuedTransaction<N: Network> {
+    pub transaction: Transaction<N>,
+    pub inserted_at: Instant,
+}
+
+/// Helper struct to track incoming transactions.
+pub(crate) struct TransactionsQueue<N: Network> {
+    pub deployments: LruCache<N::TransactionID, QueuedTransaction<N>>,
+    pub executions: LruCache<N::TransactionID, QueuedTransaction<N>>,
+    /// The policy for selecting which transaction to evict when the queue is full.
+    eviction_policy: EvictionPolicy,
+    /// The aggregate fee of the transactions in the queue, in microcredits.
+    pending_fees: u64,
+}
+
+impl<N: Network> TransactionsQueue<N> {
+    /// Initializes a new transactions queue with the given eviction policy.
+    pub fn new(eviction_policy: EvictionPolicy) -> Self {
+        Self {
+            deployments: LruCache::new(NonZeroUsize::new(CAPACITY_FOR_DEPLOYMENTS).unwrap()),
+            executions: LruCache::new(NonZeroUsize::new(CAPACITY_FOR_EXECUTIONS).unwrap()),
+            eviction_policy,
+            pending_fees: 0,
+        }
+    }
+
+    /// Returns the aggregate fee of the transactions in the queue, in microcredits.
+    pub fn pending_fees(&self) -> u64 {
+        self.pending_fees
+    }
+
+    /// Recomputes the aggregate fee of the transactions in the queue, in microcredits, without the cached total.
+    pub fn recalculate_pending_fees(&self) -> u64 {
+        self.deployments
+            .iter()
+            .chain(self.executions.iter())
+            .fold(0u64, |total, (_, entry)| total.saturating_add(fee_of(&entry.transaction)))
+    }
+
+    /// Inserts the given transaction into the queue,
+    /// returning the ID of the evicted transaction, if the queue was full.
+    pub fn insert(
+        &mut self,
+        transaction_id: N::TransactionID,
+        transaction: Transaction<N>,
+    ) -> Result<Option<N::TransactionID>, ConsensusError> {
+        let evicted_id = self.push(transaction_id, transaction)?;
+        #[cfg(feature = "metrics")]
+        {
+            metrics::increment_counter(metrics::memory_pool::TRANSACTIONS_ADDED);
+            if evicted_id.is_some() {
+                metrics::increment_counter(metrics::memory_pool::TRANSACTIONS_REMOVED);
+            }
+            self.update_metrics();
+        }
+        Ok(evicted_id)
+    }
+
+    /// Pushes the given transaction into its queue, evicting a transaction according to the eviction policy
+    /// if the queue is full. Returns the ID of the evicted transaction, if any.
+    fn push(
+        &mut self,
+        transaction_id: N::TransactionID,
+        transaction: Transaction<N>,
+    ) -> Result<Option<N::TransactionID>, ConsensusError> {
+        // Select the queue for the transaction.
+        let queue = if transaction.is_deploy() { &mut self.deployments } else { &mut self.executions };
+        // If the queue is full, evict the lowest-fee transaction, if the policy requires it.
+        let mut evicted_id = None;
+        if self.eviction_policy == EvictionPolicy::LowestFeeFirst
+            && queue.len() >= queue.cap().get()
+            && !queue.contains(&transaction_id)
+        {
+            // Note: The iterator is reversed, so that ties are broken in favor of evicting the oldest transaction.
+            let candidate =
+                queue.iter().rev().map(|(id, entry)| (*id, fee_of(&entry.transaction))).min_by_key(|(_, fee)| *fee);
+            // Ensure the transaction pays more than the transaction it would evict,
+            // so that a flood of low-fee transactions cannot push out the higher-fee ones.
+            if let Some((_, candidate_fee)) = candidate {
+                let fee = fee_of(&transaction);
+                if fee <= candidate_fee {
+                    return Err(ConsensusError::FeeTooLow { required: candidate_fee.saturating_add(1), actual: fee });
+                }
+            }
+            evicted_id = candidate.map(|(id, _)| id);
+            if let Some(entry) = evicted_id.and_then(|evicted_id| queue.pop(&evicted_id)) {
+                self.pending_fees = self.pending_fees.saturating_sub(fee_of(&entry.transaction));
+            }
+        }
+        // Insert the transaction, evicting the least-recently-used transaction if the queue is full.
+        self.pending_fees = self.pending_fees.saturating_add(fee_of(&transaction));
+        let entry = QueuedTransaction { transaction, inserted_at: Instant::now() };
+        let pushed_out = queue.push(transaction_id, entry);
+        if let Some((_, entry)) = &pushed_out {
+            self.pending_fees = self.pending_fees.saturating_sub(fee_of(&entry.transaction));
+        }
+        match pushed_out {
+            Some((id, _)) if id == transaction_id => {
+                Err(ConsensusError::TransactionAlreadyExists(fmt_id(transaction_id)))
+            }
+            Some((id, _)) => Ok(Some(id)),
+            None => Ok(evicted_id),
+        }
+    }
+
+    /// Returns the ID of a queued transaction which spends any of the inputs of the given transaction.
+    pub fn find_conflict(&self, transaction: &Transaction<N>) -> Option<N::TransactionID> {
+        let serial_numbers = transaction.serial_numbers().collect::<Vec<_>>();
+        self.deployments.iter().chain(self.executions.iter()).find_map(|(id, entry)| {
+            let is_conflict = entry.transaction.serial_numbers().any(|serial| serial_numbers.contains(&serial));
+            (*id != transaction.id() && is_conflict).then_some(*id)
+        })
+    }
+
+    /// Replaces the queued transaction with the given ID by the new transaction, if the new transaction's
+    /// fee-per-byte exceeds the existing one's by at least `min_fee_bump_percent`.
+    ///
+    /// Returns the outcome, along with the ID of the transaction that was evicted to make room, if any.
+    ///
+    /// Note: If the existing transaction has since left the queue, `ReplacementOutcome::NotReplaced` is returned.
+    /// If the new transaction belongs in the other queue (e.g. a deployment replacing an execution),
+    /// and that queue is full, a transaction is evicted from it, as for `insert`.
+    pub fn replace(
+        &mut self,
+        existing_id: &N::TransactionID,
+        transaction_id: N::TransactionID,
+        transaction: Transaction<N>,
+        min_fee_bump_percent: u64,
+    ) -> Result<(ReplacementOutcome<N>, Option<N::TransactionID>), ConsensusError> {
+        // Retrieve the existing transaction.
+        let existing = match self.deployments.peek(existing_id).or_else(|| self.executions.peek(existing_id)) {
+            Some(entry) => &entry.transaction,
+            None => return Ok((ReplacementOutcome::NotReplaced, None)),
+        };
+        // Ensure the fee-per-byte is bumped sufficiently.
+        // Note: The rates are cross-multiplied, to compare them without a division.
+        let (existing_fee, existing_size) =
+            (u128::from(*existing.fee_amount()?), existing.to_bytes_le()?.len() as u128);
+        let (fee, size) = (u128::from(*transaction.fee_amount()?), transaction.to_bytes_le()?.len() as u128);
+        let required = existing_fee * size * (100 + u128::from(min_fee_bump_percent));
+        if fee * existing_size * 100 < required {
+            return Ok((ReplacementOutcome::NotReplaced, None));
+        }
+        // Remove the existing transaction, and insert the new one in its place.
+        let existing = match self.deployments.pop(existing_id).or_else(|| self.executions.pop(existing_id)) {
+            Some(entry) => entry.transaction,
+            None => return Ok((ReplacementOutcome::NotReplaced, None)),
+        };
+        self.pending_fees = self.pending_fees.saturating_sub(fee_of(&existing));
+        let evicted_id = match self.push(transaction_id, transaction) {
+            Ok(evicted_id) => evicted_id,
+            Err(error) => {
+                // Restore the existing transaction, so that a rejected replacement does not drop it.
+                self.push(*existing_id, existing)?;
+                return Err(error);
+            }
+        };
+        #[cfg(feature = "metrics")]
+        {
+            metrics::increment_counter(metrics::memory_pool::TRANSACTIONS_REPLACED);
+            if evicted_id.is_some() {
+                metrics::increment_counter(metrics::memory_pool::TRANSACTIONS_REMOVED);
+            }
+            self.update_metrics();
+        }
+        Ok((ReplacementOutcome::Replaced(existing), evicted_id))
+    }
+
+    /// Removes the given transaction from the queue, returning `true` if it was present.
+    pub fn remove(&mut self, transaction_id: &N::TransactionID) -> bool {
+        let removed = self.deployments.pop(transaction_id).or_else(|| self.executions.pop(transaction_id));
+        if let Some(entry) = &removed {
+            self.pending_fees = self.pending_fees.saturating_sub(fee_of(&entry.transaction));
+        }
+        let is_removed = removed.is_some();
+        #[cfg(feature = "metrics")]
+        {
+            if is_removed {
+                metrics::increment_counter(metrics::memory_pool::TRANSACTIONS_REMOVED);
+                self.update_metrics();
+            }
+        }
+        is_removed
+    }
+
+    /// Removes the transactions which were confirmed, or which spend any of the confirmed serial numbers.
+    /// Returns the IDs of the removed transactions that conflict with the confirmed ones.
+    ///
+    /// Note: The confirmed transactions themselves are removed without being returned, as they were not dropped.
+    pub fn remove_conflicts(
+        &mut self,
+        confirmed_ids: &HashSet<N::TransactionID>,
+        confirmed_serial_numbers: &HashSet<Field<N>>,
+    ) -> Vec<N::TransactionID> {
+        let mut removed_ids = Vec::new();
+        for queue in [&mut self.deployments, &mut self.executions] {
+            let invalid_ids = queue
+                .iter()
+                .filter(|(id, entry)| {
+                    confirmed_ids.contains(id)
+                        || entry.transaction.serial_numbers().any(|serial| confirmed_serial_numbers.contains(serial))
+                })
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>();
+            for id in invalid_ids {
+                if let Some(entry) = queue.pop(&id) {
+                    self.pending_fees = self.pending_fees.saturating_sub(fee_of(&entry.transaction));
+                    removed_ids.push(id);
+                }
+            }
+        }
+        #[cfg(feature = "metrics")]
+        {
+            metrics::counter(metrics::memory_pool::TRANSACTIONS_REMOVED, removed_ids.len() as u64);
+            self.update_metrics();
+        }
+        // Retain the IDs of the conflicting transactions.
+        removed_ids.retain(|id| !confirmed_ids.contains(id));
+        removed_ids
+    }
+
+    /// Removes and returns the least-recently-used deployment or execution from the queue.
+    pub fn pop_lru(&mut self, select_deployment: bool) -> Option<Transaction<N>> {
+        let queue = if select_deployment { &mut self.deployments } else { &mut self.executions };
+        let transaction = queue.pop_lru().map(|(_, entry)| entry.transaction);
+        if let Some(transaction) = &transaction {
+            self.pending_fees = self.pending_fees.saturating_sub(fee_of(transaction));
+        }
+        #[cfg(feature = "metrics")]
+        {
+            if transaction.is_some() {
+                metrics::increment_counter(metrics::memory_pool::TRANSACTIONS_REMOVED);
+                self.update_metrics();
+            }
+        }
+        transaction
+    }
+
+    /// Removes and returns all the transactions in the queue, starting from the oldest deployment.
+    pub fn drain(&mut self) -> Vec<Transaction<N>> {
+        let mut transactions = Vec::with_capacity(self.deployments.len() + self.executions.len());
+        for queue in [&mut self.deployments, &mut self.executions] {
+            transactions.extend(std::iter::from_fn(|| queue.pop_lru()).map(|(_, entry)| entry.transaction));
+        }
+        self.pending_fees = 0;
+        #[cfg(feature = "metrics")]
+        {
+            metrics::counter(metrics::memory_pool::TRANSACTIONS_REMOVED, transactions.len() as u64);
+            self.update_metrics();
+        }
+        transactions
+    }
+
+    /// Removes and returns up to `limit` transactions that have been in the queue for at least the given age,
+    /// starting from the oldest.
+    pub fn pop_older_than(&mut self, age_threshold: Duration, limit: usize) -> Vec<Transaction<N>> {
+        let mut transactions = Vec::new();
+        for queue in [&mut self.deployments, &mut self.executions] {
+            // Note: The iterator is reversed, so that the oldest transactions are selected first.
+            let stuck_ids = queue
+                .iter()
+                .rev()
+                .filter(|(_, entry)| entry.inserted_at.elapsed() >= age_threshold)
+                .map(|(id, _)| *id)
+                .take(limit.saturating_sub(transactions.len()))
+                .collect::<Vec<_>>();
+            transactions.extend(stuck_ids.iter().filter_map(|id| queue.pop(id)).map(|entry| entry.transaction));
+        }
+        let expired_fees =
+            transactions.iter().fold(0u64, |total, transaction| total.saturating_add(fee_of(transaction)));
+        self.pending_fees = self.pending_fees.saturating_sub(expired_fees);
+        #[cfg(feature = "metrics")]
+        {
+            metrics::counter(metrics::memory_pool::TRANSACTIONS_REMOVED, transactions.len() as u64);
+            self.update_metrics();
+        }
+        transactions
+    }
+
+    /// Updates the gauge for the number of transactions in the queue.
+    #[cfg(feature = "metrics")]
+    fn update_metrics(&self) {
+        let num_transactions = self.deployments.len() + self.executions.len();
+        metrics::gauge(metrics::memory_pool::TRANSACTIONS_CURRENT, num_transactions as f64);
+    }
+}
+
+/// Returns the fee of the given transaction, in microcredits, or zero if it does not have one.
+pub(crate) fn fee_of<N: Network>(transaction: &Transaction<N>) -> u64 {
+    transaction.fee_amount().map(|fee| *fee).unwrap_or(0)
+}
+
+/// Returns the fee payer of the given transaction, or `None` if its fee is private or it does not have one.
+pub(crate) fn fee_payer<N: Network>(transaction: &Transaction<N>) -> Option<Address<N>> {
+    transaction.fee_transition().and_then(|fee| fee.payer())
+}
+
+impl<N: Network> Default for TransactionsQueue<N> {
+    fn default() -> Self {
+        Self::new(EvictionPolicy::default())
+    }
+}
+
+/// Helper struct to track the proof targets of the pending solutions, with their running total,
+/// along with the targets of the latest block, so the coinbase target is checked without recomputing them.
+pub(crate) struct ProofTargetCache<N: Network> {
+    /// The cumulative proof target and the coinbase target of the latest block, once known.
+    latest_block_targets: Option<(u128, u128)>,
+    /// The proof target of each pending solution.
+    solution_targets: HashMap<SolutionID<N>, u64>,
+    /// The sum of the proof targets of the pending solutions.
+    total: u128,
+}
+
+impl<N: Network> ProofTargetCache<N> {
+    /// Returns the cumulative proof target and the coinbase target of the latest block, if they are known.
+    pub fn latest_block_targets(&self) -> Option<(u128, u128)> {
+        self.latest_block_targets
+    }
+
+    /// Sets the cumulative proof target and the coinbase target of the latest block.
+    pub fn set_latest_block_targets(&mut self, cumulative_proof_target: u128, coinbase_target: u128) {
+        self.latest_block_targets = Some((cumulative_proof_target, coinbase_target));
+    }
+
+    /// Returns the sum of the proof targets of the pending solutions.
+    pub fn total(&self) -> u128 {
+        self.total
+    }
+
+    /// Returns the proof target of the given pending solution, if it is tracked.
+    pub fn get(&self, solution_id: &SolutionID<N>) -> Option<u64> {
+        self.solution_targets.get(solution_id).copied()
+    }
+
+    /// Tracks the proof target of the given pending solution.
+    pub fn insert(&mut self, solution_id: SolutionID<N>, proof_target: u64) {
+        if let Some(previous) = self.solution_targets.insert(solution_id, proof_target) {
+            self.total = self.total.saturating_sub(u128::from(previous));
+        }
+        self.total = self.total.saturating_add(u128::from(proof_target));
+    }
+
+    /// Stops tracking the given solution, if it is tracked.
+    pub fn remove(&mut self, solution_id: &SolutionID<N>) {
+        if let Some(proof_target) = self.solution_targets.remove(solution_id) {
+            self.total = self.total.saturating_sub(u128::from(proof_target));
+        }
+    }
+
+    /// Stops tracking all the pending solutions, keeping the targets of the latest block.
+    pub fn clear_solutions(&mut self) {
+        self.solution_targets.clear();
+        self.total = 0;
+    }
+}
+
+impl<N: Network> Default for ProofTargetCache<N> {
+    fn default() -> Self {
+        Self { latest_block_targets: None, solution_targets: HashMap::new(), total: 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::{
+        ledger::ledger_test_helpers::{sample_deployment_transaction, sample_execution_transaction_with_fee},
+        prelude::{Rng, TestRng},
+    };
+
+    type CurrentNetwork = snarkvm::prelude::MainnetV0;
+
+    #[test]
+    fn test_memory_pool_config() {
+        // Ensure the default configuration is valid.
+        let config = MemoryPoolConfig::default();
+        assert!(config.ensure_is_valid().is_ok());
+        assert_eq!(config.eviction_policy, EvictionPolicy::OldestFirst);
+
+        // Ensure zero limits are rejected.
+        assert!(MemoryPoolConfig { max_transactions: 0, ..config }.ensure_is_valid().is_err());
+        assert!(MemoryPoolConfig { max_solutions: 0, ..config }.ensure_is_valid().is_err());
+        assert!(MemoryPoolConfig { max_transaction_size_bytes: 0, ..config }.ensure_is_valid().is_err());
+        assert!(MemoryPoolConfig { broadcast_capacity: 0, ..config }.ensure_is_valid().is_err());
+        assert!(MemoryPoolConfig { min_fee_bump_percent: 0, ..config }.ensure_is_valid().is_err());
+    }
+
+    #[test]
+    fn test_replace_transaction() {
+        let rng = &mut TestRng::default();
+        let first = sample_execution_transaction_with_fee(false, rng);
+        let second = sample_execution_transaction_with_fee(false, rng);
+
+        let mut queue = TransactionsQueue::<CurrentNetwork>::default();
+        queue.insert(first.id(), first.clone()).unwrap();
+
+        // Ensure a replacement without a sufficient fee bump is rejected.
+        let (outcome, _) = queue.replace(&first.id(), second.id(), second.clone(), u64::MAX / 200).unwrap();
+        assert_eq!(outcome, ReplacementOutcome::NotReplaced);
+        assert!(queue.executions.contains(&first.id()));
+
+        // Ensure cycles of replacements keep a single entry in the queue.
+        let (mut existing, mut replacement) = (first, second);
+        for _ in 0..10 {
+            let (outcome, _) = queue.replace(&existing.id(), replacement.id(), replacement.clone(), 0).unwrap();
+            assert_eq!(outcome, ReplacementOutcome::Replaced(existing.clone()));
+            assert_eq!(queue.deployments.len() + queue.executions.len(), 1);
+            std::mem::swap(&mut existing, &mut replacement);
+        }
+
+        // Ensure replacing a transaction which left the queue is rejected.
+        queue.remove(&existing.id());
+        let (outcome, _) = queue.replace(&existing.id(), replacement.id(), replacement, 0).unwrap();
+        assert_eq!(outcome, ReplacementOutcome::NotReplaced);
+    }
+
+    #[test]
+    fn test_replace_into_full_queue() {
+        let rng = &mut TestRng::default();
+        let deployment = sample_deployment_transaction(false, rng);
+        let execution = sample_execution_transaction_with_fee(false, rng);
+        let replacement = sample_execution_transaction_with_fee(true, rng);
+
+        // Initialize a queue which holds a single transaction of each kind.
+        let mut queue = TransactionsQueue::<CurrentNetwork> {
+            deployments: LruCache::new(NonZeroUsize::new(1).unwrap()),
+            executions: LruCache::new(NonZeroUsize::new(1).unwrap()),
+            ..Default::default()
+        };
+        queue.insert(deployment.id(), deployment.clone()).unwrap();
+        queue.insert(execution.id(), execution.clone()).unwrap();
+
+        // Ensure replacing the deployment by an execution evicts the queued execution, and reports it.
+        let (outcome, evicted_id) = queue.replace(&deployment.id(), replacement.id(), replacement.clone(), 0).unwrap();
+        assert_eq!(outcome, ReplacementOutcome::Replaced(deployment));
+        assert_eq!(evicted_id, Some(execution.id()));
+        assert!(queue.deployments.is_empty());
+        assert!(queue.executions.contains(&replacement.id()));
+        // Ensure the fee of the evicted transaction is no longer pending.
+        assert_eq!(queue.pending_fees(), fee_of(&replacement));
+        assert_eq!(queue.pending_fees(), queue.recalculate_pending_fees());
+    }
+
+    #[test]
+    fn test_lowest_fee_first_rejects_cheaper_transaction() {
+        let rng = &mut TestRng::default();
+        let first = sample_execution_transaction_with_fee(false, rng);
+        let second = sample_execution_transaction_with_fee(true, rng);
+        // Order the transactions by fee.
+        let (cheap, expensive) = if fee_of(&first) <= fee_of(&second) { (first, second) } else { (second, first) };
+
+        // Initialize a queue which holds a single execution, and evicts the lowest-fee transaction.
+        let mut queue = TransactionsQueue::<CurrentNetwork> {
+            executions: LruCache::new(NonZeroUsize::new(1).unwrap()),
+            ..TransactionsQueue::new(EvictionPolicy::LowestFeeFirst)
+        };
+        queue.insert(expensive.id(), expensive.clone()).unwrap();
+
+        // Ensure a transaction which does not pay more than the eviction candidate is rejected.
+        assert!(matches!(
+            queue.insert(cheap.id(), cheap.clone()),
+            Err(ConsensusError::FeeTooLow { required, actual })
+                if required == fee_of(&expensive) + 1 && actual == fee_of(&cheap)
+        ));
+        // Ensure the queued transaction is kept.
+        assert!(queue.executions.contains(&expensive.id()));
+        assert!(!queue.executions.contains(&cheap.id()));
+        assert_eq!(queue.pending_fees(), fee_of(&expensive));
+        assert_eq!(queue.pending_fees(), queue.recalculate_pending_fees());
+    }
+
+    #[test]
+    fn test_pending_fees() {
+        let rng = &mut TestRng::default();
+        let first = sample_execution_transaction_with_fee(false, rng);
+        let second = sample_execution_transaction_with_fee(true, rng);
+
+        let mut queue = TransactionsQueue::<CurrentNetwork>::default();
+        assert_eq!(queue.pending_fees(), 0);
+
+        // Ensure the cached total tracks insertions.
+        queue.insert(first.id(), first.clone()).unwrap();
+        queue.insert(second.id(), second.clone()).unwrap();
+        assert_eq!(queue.pending_fees(), fee_of(&first) + fee_of(&second));
+        assert_eq!(queue.pending_fees(), queue.recalculate_pending_fees());
+        // Ensure a duplicate insertion does not double count.
+        assert!(queue.insert(first.id(), first.clone()).is_err());
+        assert_eq!(queue.pending_fees(), queue.recalculate_pending_fees());
+
+        // Ensure the cached total tracks removals.
+        assert!(queue.remove(&first.id()));
+        assert_eq!(queue.pending_fees(), fee_of(&second));
+        assert_eq!(queue.pop_lru(false).unwrap(), second);
+        assert_eq!(queue.pending_fees(), 0);
+
+        // Ensure the cached total is reset by a drain.
+        queue.insert(first.id(), first).unwrap();
+        queue.drain();
+        assert_eq!(queue.pending_fees(), 0);
+        assert_eq!(queue.recalculate_pending_fees(), 0);
+    }
+
+    #[test]
+    fn test_remove_conflicts() {
+        let rng = &mut TestRng::default();
+        // Note: A transaction with a private fee spends a record, so it has a serial number.
+        let pending = sample_execution_transaction_with_fee(true, rng);
+        let confirmed = sample_execution_transaction_with_fee(false, rng);
+        let serial_numbers = pending.serial_numbers().copied().collect::<HashSet<_>>();
+        assert!(!serial_numbers.is_empty());
+
+        let mut queue = TransactionsQueue::<CurrentNetwork>::default();
+        queue.insert(pending.id(), pending.clone()).unwrap();
+        queue.insert(confirmed.id(), confirmed.clone()).unwrap();
+
+        // Ensure an unrelated block leaves the queue untouched.
+        assert!(queue.remove_conflicts(&HashSet::new(), &HashSet::new()).is_empty());
+        assert_eq!(queue.deployments.len() + queue.executions.len(), 2);
+
+        // Ensure the conflicting transaction is reported, and the confirmed one is removed silently.
+        let confirmed_ids = HashSet::from([confirmed.id()]);
+        assert_eq!(queue.remove_conflicts(&confirmed_ids, &serial_numbers), vec![pending.id()]);
+        assert_eq!(queue.deployments.len() + queue.executions.len(), 0);
+        assert_eq!(queue.pending_fees(), 0);
+    }
+
+    #[test]
+    fn test_proof_target_cache() {
+        let rng = &mut TestRng::default();
+        let mut cache = ProofTargetCache::<CurrentNetwork>::default();
+        let (first, second) = (SolutionID::from(rng.gen::<u64>()), SolutionID::from(rng.gen::<u64>()));
+
+        // Ensure the running total follows the tracked solutions.
+        cache.insert(first, 10);
+        cache.insert(second, 20);
+        assert_eq!(cache.total(), 30);
+        // Ensure re-tracking a solution replaces its proof target.
+        cache.insert(first, 5);
+        assert_eq!(cache.total(), 25);
+        assert_eq!(cache.get(&first), Some(5));
+        // Ensure removing a solution, or an untracked one, updates the total once.
+        cache.remove(&second);
+        cache.remove(&second);
+        assert_eq!(cache.total(), 5);
+        // Ensure clearing the solutions keeps the targets of the latest block.
+        cache.set_latest_block_targets(1, 2);
+        cache.clear_solutions();
+        assert_eq!(cache.total(), 0);
+        assert_eq!(cache.get(&first), None);
+        assert_eq!(cache.latest_block_targets(), Some((1, 2)));
+    }
+}
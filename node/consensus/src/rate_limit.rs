@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm::prelude::{ensure, Result};
+
+use std::time::Instant;
+
+/// A token-bucket rate limit, for the transactions of a single submitter.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The maximum number of transactions that may be submitted in a burst.
+    pub burst: u32,
+    /// The number of transactions replenished per second, up to the burst.
+    pub per_second: u32,
+}
+
+impl RateLimit {
+    /// Ensures the burst and the replenish rate are non-zero.
+    pub fn ensure_is_valid(&self) -> Result<()> {
+        ensure!(self.burst > 0, "The rate limit burst must be non-zero");
+        ensure!(self.per_second > 0, "The rate limit replenish rate must be non-zero");
+        Ok(())
+    }
+}
+
+/// A token bucket, which tracks the submissions of a single submitter.
+pub(crate) struct TokenBucket {
+    /// The number of available tokens.
+    tokens: f64,
+    /// The time the tokens were last replenished.
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Initializes a full token bucket for the given rate limit.
+    pub fn new(limit: &RateLimit) -> Self {
+        Self { tokens: f64::from(limit.burst), last_refill: Instant::now() }
+    }
+
+    /// Replenishes the tokens for the time elapsed until `now`, and returns `true` if a token is available,
+    /// without taking it.
+    pub fn has_token_at(&mut self, limit: &RateLimit, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * f64::from(limit.per_second)).min(f64::from(limit.burst));
+        self.last_refill = now;
+        self.tokens >= 1.0
+    }
+
+    /// Replenishes the tokens for the time elapsed until `now`, and takes a token.
+    /// Returns `false` if no token is available.
+    pub fn try_take_at(&mut self, limit: &RateLimit, now: Instant) -> bool {
+        if !self.has_token_at(limit, now) {
+            return false;
+        }
+        self.tokens -= 1.0;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    #[test]
+    fn test_token_bucket() {
+        let limit = RateLimit { burst: 3, per_second: 2 };
+        let mut bucket = TokenBucket::new(&limit);
+        let start = Instant::now();
+
+        // Ensure a burst within the limit passes, and the excess is rejected.
+        assert!((0..3).all(|_| bucket.try_take_at(&limit, start)));
+        assert!(!bucket.try_take_at(&limit, start));
+        // Ensure a token is replenished after half a second.
+        assert!(bucket.try_take_at(&limit, start + Duration::from_millis(500)));
+        assert!(!bucket.try_take_at(&limit, start + Duration::from_millis(500)));
+        // Ensure the tokens are replenished up to the burst only.
+        let later = start + Duration::from_secs(60);
+        assert!((0..3).all(|_| bucket.try_take_at(&limit, later)));
+        assert!(!bucket.try_take_at(&limit, later));
+        // Ensure checking for a token does not take it.
+        let later = later + Duration::from_millis(500);
+        assert!((0..3).all(|_| bucket.has_token_at(&limit, later)));
+        assert!(bucket.try_take_at(&limit, later));
+        assert!(!bucket.has_token_at(&limit, later));
+    }
+
+    #[test]
+    fn test_rate_limit_is_valid() {
+        assert!(RateLimit { burst: 1, per_second: 1 }.ensure_is_valid().is_ok());
+        assert!(RateLimit { burst: 0, per_second: 1 }.ensure_is_valid().is_err());
+        assert!(RateLimit { burst: 1, per_second: 0 }.ensure_is_valid().is_err());
+    }
+}
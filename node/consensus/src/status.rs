@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm::{
+    ledger::{narwhal::TransmissionID, puzzle::SolutionID},
+    prelude::{Address, Network},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A diagnostic snapshot of the consensus state.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusStatus<N: Network> {
+    /// The latest block height in the ledger.
+    pub latest_height: u32,
+    /// The latest round in the ledger.
+    pub latest_round: u64,
+    /// The leader of the current even round, if one was present.
+    pub bft_leader: Option<Address<N>>,
+    /// The number of members in the current committee.
+    pub committee_size: usize,
+    /// The number of unconfirmed transmissions.
+    pub num_unconfirmed_transmissions: usize,
+    /// The number of unconfirmed transactions in the memory pool.
+    pub memory_pool_tx_count: usize,
+    /// The number of unconfirmed solutions in the memory pool.
+    pub memory_pool_solution_count: usize,
+    /// Whether the unconfirmed solutions meet the coinbase target.
+    pub is_coinbase_target_met: bool,
+}
+
+/// The requirements for a solution to be accepted, as of the latest block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolutionRequirements {
+    /// The proof target of the latest block.
+    pub latest_proof_target: u64,
+    /// The coinbase target of the latest block.
+    pub latest_coinbase_target: u64,
+    /// The epoch number of the latest block.
+    pub epoch_number: u32,
+    /// Whether the unconfirmed solutions meet the coinbase target.
+    pub coinbase_target_met: bool,
+}
+
+/// The overall health of consensus.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HealthStatus {
+    /// Consensus is running, and the latest block is recent.
+    Healthy,
+    /// Consensus is running, but the latest block is older than the configured threshold.
+    Degraded,
+    /// Consensus is not running.
+    Unhealthy,
+}
+
+/// A lightweight health report of consensus, for liveness and readiness probes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// Whether the BFT is running, and consensus is not shutting down.
+    pub bft_running: bool,
+    /// Whether the primary sender is initialized.
+    pub primary_sender_initialized: bool,
+    /// The latest block height in the ledger.
+    pub latest_height: u32,
+    /// The latest round in the ledger.
+    pub latest_round: u64,
+    /// The number of seconds since the timestamp of the latest block.
+    pub seconds_since_last_block: u64,
+    /// The number of unconfirmed solutions and transactions, in the inbound queues and the memory pool.
+    pub mempool_size: usize,
+    /// Whether all of the consensus tasks are alive.
+    pub handles_alive: bool,
+    /// The overall health.
+    pub overall: HealthStatus,
+}
+
+/// A read-only snapshot of the IDs in the inbound queues and the memory pool.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryPoolSnapshot<N: Network> {
+    /// The IDs of the solutions in the inbound queue.
+    pub queued_solution_ids: Vec<SolutionID<N>>,
+    /// The IDs of the transactions in the inbound queue.
+    pub queued_transaction_ids: Vec<N::TransactionID>,
+    /// The IDs of the unconfirmed transmissions in the memory pool.
+    pub unconfirmed_transmission_ids: Vec<TransmissionID<N>>,
+}
+
+impl<N: Network> MemoryPoolSnapshot<N> {
+    /// Returns the number of solutions in the inbound queue.
+    pub fn num_queued_solutions(&self) -> usize {
+        self.queued_solution_ids.len()
+    }
+
+    /// Returns the number of transactions in the inbound queue.
+    pub fn num_queued_transactions(&self) -> usize {
+        self.queued_transaction_ids.len()
+    }
+
+    /// Returns the number of unconfirmed transmissions in the memory pool.
+    pub fn num_unconfirmed_transmissions(&self) -> usize {
+        self.unconfirmed_transmission_ids.len()
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use indexmap::IndexMap;
+use parking_lot::Mutex;
+use tokio::task::JoinHandle;
+
+/// A registry of the long-running tasks spawned by consensus, keyed by name.
+#[derive(Debug, Default)]
+pub struct TaskRegistry {
+    /// The handles of the registered tasks.
+    handles: Mutex<IndexMap<&'static str, JoinHandle<()>>>,
+}
+
+impl TaskRegistry {
+    /// Registers the given task under the given name.
+    /// If a task is already registered under the name, it is aborted and replaced.
+    pub fn register(&self, name: &'static str, handle: JoinHandle<()>) {
+        if let Some(previous) = self.handles.lock().insert(name, handle) {
+            warn!("Replacing the existing '{name}' task");
+            previous.abort();
+        }
+    }
+
+    /// Aborts the task registered under the given name.
+    /// Returns `true` if a task was registered under the name.
+    pub fn abort(&self, name: &str) -> bool {
+        match self.handles.lock().shift_remove(name) {
+            Some(handle) => {
+                handle.abort();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the task registered under the given name has not exited.
+    pub fn is_running(&self, name: &str) -> bool {
+        self.handles.lock().get(name).map_or(false, |handle| !handle.is_finished())
+    }
+
+    /// Returns `true` if at least one task is registered, and none of the registered tasks have exited.
+    pub fn all_running(&self) -> bool {
+        let handles = self.handles.lock();
+        !handles.is_empty() && handles.values().all(|handle| !handle.is_finished())
+    }
+
+    /// Aborts all of the registered tasks.
+    pub fn abort_all(&self) {
+        self.handles.lock().drain(..).for_each(|(_, handle)| handle.abort());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::future::pending;
+
+    #[tokio::test]
+    async fn test_task_registry() {
+        let tasks = TaskRegistry::default();
+        assert!(!tasks.all_running());
+        assert!(!tasks.is_running("a"));
+        assert!(!tasks.abort("a"));
+
+        // Register two long-running tasks.
+        tasks.register("a", tokio::spawn(pending()));
+        tasks.register("b", tokio::spawn(pending()));
+        assert!(tasks.is_running("a"));
+        assert!(tasks.is_running("b"));
+        assert!(tasks.all_running());
+
+        // Ensure only the named task is aborted.
+        assert!(tasks.abort("a"));
+        assert!(!tasks.is_running("a"));
+        assert!(tasks.is_running("b"));
+
+        // Ensure a task that exited is not running.
+        tasks.register("c", tokio::spawn(async {}));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        assert!(!tasks.is_running("c"));
+        assert!(!tasks.all_running());
+
+        // Ensure all of the tasks are aborted.
+        tasks.abort_all();
+        assert!(!tasks.is_running("b"));
+        assert!(!tasks.abort("b"));
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A test-only harness, which drives a consensus instance without networking.
+//!
+//! Note: This module is only available with the `testing` feature, and must not be used in production.
+
+use crate::{Consensus, ConsensusConfig, ConsensusError};
+use snarkos_account::Account;
+use snarkos_node_bft::helpers::{init_primary_channels, now, PrimaryReceiver};
+use snarkos_node_bft_ledger_service::{CoreLedgerService, LedgerService};
+use snarkos_node_bft_storage_service::BFTMemoryService;
+use snarkvm::{
+    ledger::{
+        block::{Block, Transaction},
+        narwhal::{BatchCertificate, BatchHeader, Subdag, Transmission, TransmissionID},
+        puzzle::Solution,
+        store::helpers::memory::ConsensusMemory,
+        Ledger,
+    },
+    prelude::{anyhow, bail, Network, Result},
+};
+
+use aleo_std::StorageMode;
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::Mutex;
+use rand::{CryptoRng, Rng};
+use std::{
+    collections::BTreeMap,
+    sync::{atomic::AtomicBool, Arc},
+};
+use tokio::sync::oneshot;
+
+/// A test-only harness, which builds a consensus instance whose primary is replaced by an in-process collector.
+/// Transmissions may be injected, and subdags committed, deterministically and without networking.
+pub struct ConsensusTestbed<N: Network> {
+    /// The consensus instance.
+    consensus: Consensus<N>,
+    /// The accounts of the committee members, which sign the certificates of the assembled subdags.
+    accounts: Vec<Account<N>>,
+    /// The transmissions that were sent to the primary, and are not yet committed.
+    pending: Arc<Mutex<IndexMap<TransmissionID<N>, Transmission<N>>>>,
+}
+
+impl<N: Network> ConsensusTestbed<N> {
+    /// Initializes a new testbed over the given ledger, with the given committee member accounts.
+    /// The consensus instance runs as the first account.
+    ///
+    /// Note: This must be called within a Tokio runtime, as the collector tasks are spawned on initialization.
+    pub fn new(ledger: Arc<dyn LedgerService<N>>, accounts: Vec<Account<N>>, config: ConsensusConfig) -> Result<Self> {
+        let Some(account) = accounts.first().cloned() else {
+            bail!("The testbed requires at least one committee member account");
+        };
+        // Initialize the consensus, with in-memory transmissions and without a persisted memory pool.
+        config.ensure_is_valid::<N>()?;
+        let transmissions = Arc::new(BFTMemoryService::new());
+        let config = ConsensusConfig { persist_memory_pool: false, ..config };
+        let consensus =
+            Consensus::with_transmissions(account, ledger, transmissions, None, &[], StorageMode::Production, config)?;
+        // Replace the primary with a collector of the transmissions.
+        let (primary_sender, primary_receiver) = init_primary_channels();
+        consensus.primary_sender.set(primary_sender).map_err(|_| anyhow!("Primary sender already set"))?;
+        let PrimaryReceiver { mut rx_unconfirmed_solution, mut rx_unconfirmed_transaction, .. } = primary_receiver;
+        let pending: Arc<Mutex<IndexMap<TransmissionID<N>, Transmission<N>>>> = Default::default();
+
+        let pending_ = pending.clone();
+        consensus.spawn("testbed_solutions", async move {
+            while let Some((solution_id, solution, callback)) = rx_unconfirmed_solution.recv().await {
+                pending_.lock().insert(TransmissionID::Solution(solution_id), Transmission::Solution(solution));
+                callback.send(Ok(())).ok();
+            }
+        });
+        let pending_ = pending.clone();
+        consensus.spawn("testbed_transactions", async move {
+            while let Some((transaction_id, transaction, callback)) = rx_unconfirmed_transaction.recv().await {
+                let transmission = Transmission::Transaction(transaction);
+                pending_.lock().insert(TransmissionID::Transaction(transaction_id), transmission);
+                callback.send(Ok(())).ok();
+            }
+        });
+
+        Ok(Self { consensus, accounts, pending })
+    }
+
+    /// Initializes a new testbed over an in-memory ledger, which starts from the given genesis block.
+    ///
+    /// Note: The genesis block must bond the committee of the given accounts, or the assembled subdags are rejected.
+    pub fn with_memory_ledger(genesis: Block<N>, accounts: Vec<Account<N>>, config: ConsensusConfig) -> Result<Self> {
+        let ledger = Ledger::<N, ConsensusMemory<N>>::load(genesis, StorageMode::Development(0))?;
+        let ledger = Arc::new(CoreLedgerService::new(ledger, Arc::new(AtomicBool::new(false))));
+        Self::new(ledger, accounts, config)
+    }
+
+    /// Returns the consensus instance.
+    pub const fn consensus(&self) -> &Consensus<N> {
+        &self.consensus
+    }
+
+    /// Returns the transmissions that were sent to the primary, and are not yet committed.
+    pub fn pending_transmissions(&self) -> IndexMap<TransmissionID<N>, Transmission<N>> {
+        self.pending.lock().clone()
+    }
+
+    /// Adds the given transaction to consensus, which forwards it to the collector.
+    pub async fn inject_transaction(&self, transaction: Transaction<N>) -> Result<(), ConsensusError> {
+        self.consensus.add_unconfirmed_transaction(transaction).await
+    }
+
+    /// Adds the given solution to consensus, which forwards it to the collector.
+    pub async fn inject_solution(&self, solution: Solution<N>) -> Result<(), ConsensusError> {
+        self.consensus.add_unconfirmed_solution(solution).await
+    }
+
+    /// Assembles a committed subdag for the next even round, whose leader certificate contains the given transmissions.
+    ///
+    /// The subdag consists of a certificate from every committee member in the round before the anchor round,
+    /// and the leader certificate in the anchor round, each signed by every other committee member.
+    pub fn assemble_subdag<R: Rng + CryptoRng>(
+        &self,
+        transmission_ids: IndexSet<TransmissionID<N>>,
+        rng: &mut R,
+    ) -> Result<Subdag<N>> {
+        let ledger = &self.consensus.ledger;
+        // Determine the anchor round, which is the next even round after the latest round.
+        let anchor_round = (ledger.latest_round() / 2 + 1) * 2;
+        // Retrieve the leader of the anchor round.
+        let committee = ledger.get_committee_lookback_for_round(anchor_round)?;
+        let leader = committee.get_leader(anchor_round)?;
+        let Some(leader_account) = self.accounts.iter().find(|account| account.address() == leader) else {
+            bail!("The leader of round {anchor_round} is not one of the testbed accounts");
+        };
+
+        // Certify a batch from every committee member in the round before the anchor round.
+        let (timestamp, committee_id) = (now(), committee.id());
+        let mut previous_certificates = IndexSet::with_capacity(self.accounts.len());
+        for account in &self.accounts {
+            let batch_header = BatchHeader::new(
+                account.private_key(),
+                anchor_round - 1,
+                timestamp,
+                committee_id,
+                Default::default(),
+                Default::default(),
+                rng,
+            )?;
+            previous_certificates.insert(self.certify(batch_header, rng)?);
+        }
+        // Certify the leader batch, which contains the transmissions, in the anchor round.
+        let batch_header = BatchHeader::new(
+            leader_account.private_key(),
+            anchor_round,
+            timestamp,
+            committee_id,
+            transmission_ids,
+            previous_certificates.iter().map(|certificate| certificate.id()).collect(),
+            rng,
+        )?;
+        let leader_certificate = self.certify(batch_header, rng)?;
+
+        let mut subdag = BTreeMap::new();
+        subdag.insert(anchor_round - 1, previous_certificates);
+        subdag.insert(anchor_round, IndexSet::from([leader_certificate]));
+        Subdag::from(subdag)
+    }
+
+    /// Commits the given subdag, with its transmissions taken from the pending transmissions,
+    /// and waits for consensus to advance to the next block.
+    pub async fn commit_subdag(&self, subdag: Subdag<N>) -> Result<Block<N>> {
+        // Retrieve the transmissions of the subdag.
+        let transmissions = {
+            let mut pending = self.pending.lock();
+            let mut transmissions = IndexMap::new();
+            for transmission_id in subdag.values().flatten().flat_map(|certificate| certificate.transmission_ids()) {
+                match pending.shift_remove(transmission_id) {
+                    Some(transmission) => transmissions.insert(*transmission_id, transmission),
+                    None => bail!("Missing the pending transmission for '{transmission_id}'"),
+                };
+            }
+            transmissions
+        };
+        // Advance to the next block.
+        let (callback_sender, callback_receiver) = oneshot::channel();
+        self.consensus.process_bft_subdag(subdag, transmissions, callback_sender).await;
+        callback_receiver.await??;
+        Ok(self.consensus.ledger.latest_block())
+    }
+
+    /// Commits all the pending transmissions in a new subdag, and returns the resulting block.
+    pub async fn advance<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Block<N>> {
+        let transmission_ids = self.pending.lock().keys().copied().collect();
+        let subdag = self.assemble_subdag(transmission_ids, rng)?;
+        self.commit_subdag(subdag).await
+    }
+
+    /// Returns the certificate for the given batch header, signed by every committee member other than its author.
+    fn certify<R: Rng + CryptoRng>(&self, batch_header: BatchHeader<N>, rng: &mut R) -> Result<BatchCertificate<N>> {
+        let signatures = self
+            .accounts
+            .iter()
+            .filter(|account| account.address() != batch_header.author())
+            .map(|account| account.sign(&[batch_header.batch_id()], rng))
+            .collect::<Result<IndexSet<_>>>()?;
+        BatchCertificate::from(batch_header, signatures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkos_node_bft_ledger_service::MockLedgerService;
+    use snarkvm::{
+        ledger::committee::{Committee, MIN_VALIDATOR_STAKE},
+        prelude::TestRng,
+    };
+
+    type CurrentNetwork = snarkvm::prelude::MainnetV0;
+
+    #[tokio::test]
+    async fn test_assemble_subdag() {
+        let rng = &mut TestRng::default();
+        // Initialize a committee of the testbed accounts.
+        let accounts = (0..4).map(|_| Account::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+        let members = accounts.iter().map(|account| (account.address(), (MIN_VALIDATOR_STAKE, false))).collect();
+        let committee = Committee::new(0u64, members).unwrap();
+        let ledger = Arc::new(MockLedgerService::new(committee.clone()));
+        let testbed = ConsensusTestbed::new(ledger, accounts, ConsensusConfig::default()).unwrap();
+
+        // Ensure the subdag is anchored at the next even round, by the elected leader.
+        let subdag = testbed.assemble_subdag(IndexSet::new(), rng).unwrap();
+        assert_eq!(subdag.anchor_round(), 2);
+        assert_eq!(subdag.leader_certificate().author(), committee.get_leader(2).unwrap());
+        // Ensure every certificate is signed by every other committee member.
+        for certificate in subdag.values().flatten() {
+            assert_eq!(certificate.signatures().len(), 3);
+        }
+        // Ensure committing a subdag with unknown transmissions fails.
+        let transmission_ids = IndexSet::from([TransmissionID::Ratification]);
+        let subdag = testbed.assemble_subdag(transmission_ids, rng).unwrap();
+        assert!(testbed.commit_subdag(subdag).await.is_err());
+    }
+}
@@ -12,9 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-pub(super) const COUNTER_NAMES: [&str; 1] = [bft::LEADERS_ELECTED];
+pub(super) const COUNTER_NAMES: [&str; 6] = [
+    bft::LEADERS_ELECTED,
+    memory_pool::SOLUTIONS_ADDED,
+    memory_pool::SOLUTIONS_REMOVED,
+    memory_pool::TRANSACTIONS_ADDED,
+    memory_pool::TRANSACTIONS_REMOVED,
+    memory_pool::TRANSACTIONS_REPLACED,
+];
 
-pub(super) const GAUGE_NAMES: [&str; 18] = [
+pub(super) const GAUGE_NAMES: [&str; 20] = [
     bft::CONNECTED,
     bft::CONNECTING,
     bft::LAST_STORED_ROUND,
@@ -29,16 +36,19 @@ pub(super) const GAUGE_NAMES: [&str; 18] = [
     consensus::UNCONFIRMED_SOLUTIONS,
     consensus::UNCONFIRMED_TRANSACTIONS,
     consensus::UNCONFIRMED_TRANSMISSIONS,
+    memory_pool::SOLUTIONS_CURRENT,
+    memory_pool::TRANSACTIONS_CURRENT,
     router::CONNECTED,
     router::CANDIDATE,
     router::RESTRICTED,
     tcp::TCP_TASKS,
 ];
 
-pub(super) const HISTOGRAM_NAMES: [&str; 7] = [
+pub(super) const HISTOGRAM_NAMES: [&str; 8] = [
     bft::COMMIT_ROUNDS_LATENCY,
     consensus::CERTIFICATE_COMMIT_LATENCY,
     consensus::BLOCK_LATENCY,
+    consensus::COMMITTED_TRANSMISSIONS_PER_ROUND,
     tcp::NOISE_CODEC_ENCRYPTION_TIME,
     tcp::NOISE_CODEC_DECRYPTION_TIME,
     tcp::NOISE_CODEC_ENCRYPTION_SIZE,
@@ -65,6 +75,7 @@ pub mod blocks {
 pub mod consensus {
     pub const CERTIFICATE_COMMIT_LATENCY: &str = "snarkos_consensus_certificate_commit_latency_secs";
     pub const COMMITTED_CERTIFICATES: &str = "snarkos_consensus_committed_certificates_total";
+    pub const COMMITTED_TRANSMISSIONS_PER_ROUND: &str = "snarkos_consensus_committed_transmissions_per_round";
     pub const LAST_COMMITTED_ROUND: &str = "snarkos_consensus_last_committed_round";
     pub const BLOCK_LATENCY: &str = "snarkos_consensus_block_latency_secs";
     pub const UNCONFIRMED_TRANSACTIONS: &str = "snarkos_consensus_unconfirmed_transactions_total";
@@ -72,6 +83,16 @@ pub mod consensus {
     pub const UNCONFIRMED_SOLUTIONS: &str = "snarkos_consensus_unconfirmed_solutions_total";
 }
 
+pub mod memory_pool {
+    pub const SOLUTIONS_ADDED: &str = "snarkos_memory_pool_solutions_added_total";
+    pub const SOLUTIONS_REMOVED: &str = "snarkos_memory_pool_solutions_removed_total";
+    pub const SOLUTIONS_CURRENT: &str = "snarkos_memory_pool_solutions_current";
+    pub const TRANSACTIONS_ADDED: &str = "snarkos_memory_pool_transactions_added_total";
+    pub const TRANSACTIONS_REMOVED: &str = "snarkos_memory_pool_transactions_removed_total";
+    pub const TRANSACTIONS_CURRENT: &str = "snarkos_memory_pool_transactions_current";
+    pub const TRANSACTIONS_REPLACED: &str = "snarkos_memory_pool_transactions_replaced_total";
+}
+
 pub mod router {
     pub const CONNECTED: &str = "snarkos_router_connected_total";
     pub const CANDIDATE: &str = "snarkos_router_candidate_total";
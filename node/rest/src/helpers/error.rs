@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use snarkos_node_consensus::ConsensusError;
+
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
@@ -31,3 +33,9 @@ impl From<anyhow::Error> for RestError {
         Self(err.to_string())
     }
 }
+
+impl From<ConsensusError> for RestError {
+    fn from(err: ConsensusError) -> Self {
+        Self(err.to_string())
+    }
+}
@@ -182,6 +182,7 @@ impl<N: Network, C: ConsensusStorage<N>, R: Routing<N>> Rest<N, C, R> {
             .route("/mainnet/statePath/:commitment", get(Self::get_state_path_for_commitment))
             .route("/mainnet/stateRoot/latest", get(Self::get_state_root_latest))
             .route("/mainnet/committee/latest", get(Self::get_committee_latest))
+            .route("/mainnet/certificate/:id", get(Self::get_certificate))
 
             // Pass in `Rest` to make things convenient.
             .with_state(self.clone())
@@ -256,6 +256,20 @@ impl<N: Network, C: ConsensusStorage<N>, R: Routing<N>> Rest<N, C, R> {
         Ok(ErasedJson::pretty(rest.ledger.latest_committee()?))
     }
 
+    // GET /mainnet/certificate/{certificateID}
+    pub(crate) async fn get_certificate(
+        State(rest): State<Self>,
+        Path(certificate_id): Path<Field<N>>,
+    ) -> Result<ErasedJson, RestError> {
+        match rest.consensus {
+            Some(consensus) => match consensus.get_certificate(&certificate_id) {
+                Some(certificate) => Ok(ErasedJson::pretty(certificate)),
+                None => Err(RestError(format!("Certificate '{certificate_id}' was not found"))),
+            },
+            None => Err(RestError("Route isn't available for this node type".to_string())),
+        }
+    }
+
     // GET /mainnet/peers/count
     pub(crate) async fn get_peers_count(State(rest): State<Self>) -> ErasedJson {
         ErasedJson::pretty(rest.routing.router().number_of_connected_peers())